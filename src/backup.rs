@@ -0,0 +1,271 @@
+use anyhow::Context;
+use chrono::{Local, NaiveDateTime};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+use crate::i18n::t;
+
+/// 백업 디렉토리 이름 (대상 파일과 같은 디렉토리 아래에 생성)
+pub const BACKUP_DIR_NAME: &str = ".initai-backup";
+
+/// 백업 파일명의 시각 형식
+const TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S%3f";
+
+/// 백업 정보
+#[derive(Debug, Clone)]
+pub struct Backup {
+    /// 백업 식별자 (백업 파일명)
+    pub id: String,
+    pub created_at: NaiveDateTime,
+    pub path: PathBuf,
+}
+
+/// 임시 파일에 쓴 뒤 이름을 바꿔 원자적으로 파일 작성
+pub fn write_atomic(path: &Path, content: impl AsRef<[u8]>) -> Result<()> {
+    let temp_path = temp_path_for(path)?;
+
+    let result =
+        write_temp(&temp_path, content.as_ref()).and_then(|()| fs::rename(&temp_path, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+
+    result.with_context(|| t!("backup.write_failed", path.display()))?;
+    Ok(())
+}
+
+/// 임시 파일에 쓴 뒤 하드 링크로 새 파일 작성 (이미 있으면 `AlreadyExists`, 기존 파일은 그대로)
+///
+/// 작성 도중 중단되어도 대상 경로에는 완성된 파일만 생김
+pub fn create_atomic(path: &Path, content: impl AsRef<[u8]>) -> io::Result<()> {
+    let temp_path =
+        temp_path_for(path).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    let result =
+        write_temp(&temp_path, content.as_ref()).and_then(|()| fs::hard_link(&temp_path, path));
+    let _ = fs::remove_file(&temp_path);
+    result
+}
+
+/// 대상 파일과 같은 디렉토리의 임시 파일 경로 (같은 파일 시스템이어야 이름 변경, 링크 가능)
+fn temp_path_for(path: &Path) -> Result<PathBuf> {
+    let dir = path
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let file_name = path
+        .file_name()
+        .and_then(|s| s.to_str())
+        .ok_or_else(|| Error::Validation(t!("backup.invalid_path", path.display())))?;
+    Ok(dir.join(format!(".{}.tmp-{}", file_name, std::process::id())))
+}
+
+/// 임시 파일에 내용을 쓰고 디스크에 반영
+fn write_temp(temp_path: &Path, content: &[u8]) -> io::Result<()> {
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(temp_path)?;
+    file.write_all(content)?;
+    file.sync_all()
+}
+
+/// 대상 파일의 백업 디렉토리
+fn backup_dir(target: &Path) -> PathBuf {
+    target
+        .parent()
+        .unwrap_or(Path::new("."))
+        .join(BACKUP_DIR_NAME)
+}
+
+/// 기존 파일을 타임스탬프가 붙은 백업으로 복사 (파일이 없으면 None)
+pub fn backup_file(target: &Path) -> Result<Option<Backup>> {
+    if !target.exists() {
+        return Ok(None);
+    }
+
+    let file_name = target
+        .file_name()
+        .and_then(|s| s.to_str())
+        .ok_or_else(|| anyhow::anyhow!(t!("backup.invalid_path", target.display())))?;
+
+    let dir = backup_dir(target);
+    fs::create_dir_all(&dir)
+        .with_context(|| t!("backup.create_dir_failed", dir.display()))?;
+
+    // 같은 시각의 백업이 이미 있으면 다음 시각으로 생성
+    let (created_at, id, path) = loop {
+        let created_at = Local::now().naive_local();
+        let id = format!("{}_{}", created_at.format(TIMESTAMP_FORMAT), file_name);
+        let path = dir.join(&id);
+
+        if !path.exists() {
+            break (created_at, id, path);
+        }
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    };
+
+    fs::copy(target, &path)
+        .with_context(|| t!("backup.copy_failed", target.display(), path.display()))?;
+
+    Ok(Some(Backup {
+        id,
+        created_at,
+        path,
+    }))
+}
+
+/// 백업 삭제 (백업 디렉토리가 비면 디렉토리도 삭제)
+pub fn remove_backup(backup: &Backup) -> Result<()> {
+    fs::remove_file(&backup.path).with_context(|| t!("backup.remove_failed", backup.path.display()))?;
+    if let Some(dir) = backup.path.parent() {
+        // 다른 백업이 남아 있으면 실패하므로 무시
+        let _ = fs::remove_dir(dir);
+    }
+    Ok(())
+}
+
+/// 대상 파일의 백업 목록 조회 (최신순)
+pub fn list_backups(target: &Path) -> Result<Vec<Backup>> {
+    let dir = backup_dir(target);
+    let Some(file_name) = target.file_name().and_then(|s| s.to_str()) else {
+        return Ok(Vec::new());
+    };
+
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut backups = Vec::new();
+    for entry in fs::read_dir(&dir)
+        .with_context(|| t!("backup.read_dir_failed", dir.display()))?
+    {
+        let entry = entry.with_context(|| t!("backup.read_dir_failed", dir.display()))?;
+        let Some(id) = entry.file_name().to_str().map(|s| s.to_string()) else {
+            continue;
+        };
+
+        let Some((timestamp, name)) = id.split_once('_') else {
+            continue;
+        };
+        if name != file_name {
+            continue;
+        }
+        let Ok(created_at) = NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT) else {
+            continue;
+        };
+
+        backups.push(Backup {
+            id: id.clone(),
+            created_at,
+            path: entry.path(),
+        });
+    }
+
+    backups.sort_by(|a, b| b.id.cmp(&a.id));
+
+    Ok(backups)
+}
+
+/// 복원할 백업 찾기 (`id`를 지정하지 않으면 가장 최근 백업)
+pub fn find_backup(target: &Path, id: Option<&str>) -> Result<Backup> {
+    let backups = list_backups(target)?;
+
+    let backup = match id {
+        Some(id) => backups.into_iter().find(|b| b.id == id),
+        None => backups.into_iter().next(),
+    }
+    .ok_or_else(|| match id {
+        Some(id) => anyhow::anyhow!(t!("backup.not_found", id)),
+        None => anyhow::anyhow!(t!("backup.none", target.display())),
+    })?;
+
+    Ok(backup)
+}
+
+/// 백업으로 대상 파일 복원 (현재 파일은 복원 전에 다시 백업, 복원한 내용 반환)
+pub fn restore_from(target: &Path, backup: &Backup) -> Result<String> {
+    let content = fs::read_to_string(&backup.path)
+        .with_context(|| t!("backup.read_failed", backup.path.display()))?;
+
+    backup_file(target)?;
+    write_atomic(target, &content)?;
+
+    Ok(content)
+}
+
+/// 백업으로 대상 파일 복원 (현재 파일은 복원 전에 다시 백업)
+///
+/// `id`를 지정하지 않으면 가장 최근 백업으로 복원
+pub fn restore_backup(target: &Path, id: Option<&str>) -> Result<Backup> {
+    let backup = find_backup(target, id)?;
+    restore_from(target, &backup)?;
+
+    Ok(backup)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_write_atomic() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("TEAM_RULES.md");
+
+        write_atomic(&path, "first").unwrap();
+        write_atomic(&path, "second").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "second");
+        // 임시 파일이 남지 않음
+        assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_create_atomic() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("Rules.md");
+
+        create_atomic(&path, "first").unwrap();
+        let err = create_atomic(&path, "second").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+
+        // 기존 파일은 그대로이고 임시 파일이 남지 않음
+        assert_eq!(fs::read_to_string(&path).unwrap(), "first");
+        assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_backup_and_restore() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("TEAM_RULES.md");
+
+        assert!(backup_file(&path).unwrap().is_none());
+
+        fs::write(&path, "v1").unwrap();
+        let first = backup_file(&path).unwrap().unwrap();
+        fs::write(&path, "v2").unwrap();
+        backup_file(&path).unwrap();
+        fs::write(&path, "v3").unwrap();
+
+        let backups = list_backups(&path).unwrap();
+        assert_eq!(backups.len(), 2);
+        assert_eq!(backups[1].id, first.id);
+
+        // 최신 백업으로 복원
+        restore_backup(&path, None).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "v2");
+
+        // 지정한 백업으로 복원 (복원 전 파일도 백업됨)
+        restore_backup(&path, Some(&first.id)).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "v1");
+        assert_eq!(list_backups(&path).unwrap().len(), 4);
+
+        assert!(restore_backup(&path, Some("missing")).is_err());
+        assert!(find_backup(&temp_dir.path().join("OTHER.md"), None).is_err());
+    }
+}
@@ -1,10 +1,36 @@
-use clap::{Parser, Subcommand};
+use clap::builder::{PossibleValue, PossibleValuesParser, RangedU64ValueParser, StyledStr};
+use clap::{Parser, Subcommand, ValueEnum};
+use clap_complete::engine::{ArgValueCandidates, CompletionCandidate};
 use std::path::PathBuf;
 
+use init_ai::i18n::{t, Lang};
+use init_ai::name::{self, TemplateName, TemplateSpec};
+use init_ai::{config, embedded, render, template};
+
+/// AI 팀 협업 규칙 파일을 프로젝트에 추가하는 CLI 도구
 #[derive(Parser)]
-#[command(name = "initai")]
-#[command(about = "AI 팀 협업 규칙 파일을 프로젝트에 추가하는 CLI 도구", long_about = None)]
+#[command(name = "initai", long_about = None)]
 pub struct Cli {
+    /// 출력 형식 (json: 스크립트, 편집기 연동용)
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+
+    /// 안내 메시지를 생략하고 결과와 오류만 출력
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+
+    /// 확인 질문에 모두 예로 답함 (터미널이 아닌 환경에서 덮어쓰기, 삭제 등을 진행할 때 필요)
+    #[arg(short, long, global = true)]
+    pub yes: bool,
+
+    /// 오류 발생 시 원인 체인까지 모두 표시
+    #[arg(short, long, global = true)]
+    pub verbose: bool,
+
+    /// 메시지 언어 (생략 시 설정 파일의 language, LANG 환경 변수 순)
+    #[arg(long, global = true, value_enum)]
+    pub lang: Option<Lang>,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -13,16 +39,53 @@ pub struct Cli {
 pub enum Commands {
     /// 템플릿 목록 보기
     #[command(alias = "ls")]
-    List,
+    List {
+        /// 설치된 모든 버전 표시
+        #[arg(long)]
+        all_versions: bool,
+
+        /// 설명과 추정 토큰 수 함께 표시
+        #[arg(short, long)]
+        long: bool,
+    },
 
     /// 프로젝트 초기화
     Init {
-        /// 사용할 템플릿 이름
-        template: Option<String>,
+        /// 사용할 템플릿 이름 (특정 버전은 `이름@버전`, 버전 없는 파일은 `이름@`, 생략 시 최신 버전)
+        #[arg(add = ArgValueCandidates::new(template_candidates))]
+        template: Option<TemplateSpec>,
 
         /// 템플릿 이름 (--template 플래그 사용)
-        #[arg(short, long)]
-        template_flag: Option<String>,
+        #[arg(short, long = "template", value_name = "TEMPLATE", add = ArgValueCandidates::new(template_candidates))]
+        template_flag: Option<TemplateSpec>,
+
+        /// 템플릿 변수 지정 (여러 번 사용 가능)
+        #[arg(long = "var", value_name = "KEY=VALUE")]
+        vars: Vec<String>,
+
+        /// 사용할 번역본 로케일 (예: en, ko, en-US. 번역본이 없으면 기본 파일)
+        #[arg(long, value_parser = name::parse_locale)]
+        locale: Option<String>,
+    },
+
+    /// 현재 프로젝트에서 initai가 관리하는 파일 상태 보기
+    Status,
+
+    /// 규칙 파일이 템플릿으로 생성한 결과와 같은지 확인 (CI용, 파일을 쓰지 않음)
+    Check,
+
+    /// 설정, 템플릿, 프로젝트 상태 진단
+    Doctor,
+
+    /// 백업된 이전 버전으로 규칙 파일 복원
+    Restore {
+        /// 복원할 백업 (생략 시 가장 최근 백업)
+        #[arg(conflicts_with = "list")]
+        backup: Option<String>,
+
+        /// 백업 목록 보기
+        #[arg(long)]
+        list: bool,
     },
 
     /// 템플릿 관리
@@ -31,30 +94,305 @@ pub enum Commands {
         action: TemplateAction,
     },
 
+    /// 셸 자동 완성 스크립트 출력 (템플릿 이름도 설치된 목록에서 완성)
+    Completions {
+        /// 대상 셸
+        shell: CompletionShell,
+    },
+
+    /// 명령줄 정의로 매뉴얼 페이지와 마크다운 명령어 레퍼런스 생성
+    Man {
+        /// 파일을 작성할 디렉토리 (생략 시 최상위 페이지를 표준 출력에 표시)
+        #[arg(long, value_name = "DIR")]
+        out_dir: Option<PathBuf>,
+
+        /// 매뉴얼 페이지 대신 마크다운 명령어 레퍼런스 생성
+        #[arg(long)]
+        markdown: bool,
+    },
+
     /// 설정 관리
     Config {
         /// 템플릿 저장 경로 설정
         #[arg(long)]
         set_template_path: Option<PathBuf>,
+
+        #[command(subcommand)]
+        action: Option<ConfigAction>,
     },
 }
 
+#[derive(Subcommand)]
+pub enum ConfigAction {
+    /// 현재 설정 보기
+    #[command(alias = "ls")]
+    List,
+}
+
 #[derive(Subcommand)]
 pub enum TemplateAction {
+    /// 템플릿 목록 보기
+    #[command(alias = "ls")]
+    List {
+        /// 설치된 모든 버전 표시
+        #[arg(long)]
+        all_versions: bool,
+
+        /// 설명과 추정 토큰 수 함께 표시
+        #[arg(short, long)]
+        long: bool,
+    },
+
     /// 템플릿 설치
     Install {
         /// 설치할 템플릿 파일 경로
-        file_path: PathBuf,
+        #[arg(required_unless_present_any = ["builtin", "all"])]
+        file_path: Option<PathBuf>,
 
-        /// 템플릿 이름 (지정하지 않으면 파일명 사용)
-        #[arg(short, long)]
-        name: Option<String>,
+        /// 템플릿 이름 (`이름@버전`으로 버전, `이름.<로케일>`로 번역본 지정, 지정하지 않으면 파일명 사용)
+        #[arg(short, long, value_parser = name::parse_localized)]
+        name: Option<(TemplateSpec, Option<String>)>,
+
+        /// 내장 템플릿 설치 (삭제한 내장 템플릿 복원)
+        #[arg(
+            long,
+            value_name = "NAME",
+            ignore_case = true,
+            value_parser = PossibleValuesParser::new(embedded::builtin_names()),
+            conflicts_with_all = ["file_path", "name"]
+        )]
+        builtin: Option<String>,
+
+        /// 모든 내장 템플릿 설치
+        #[arg(long, conflicts_with_all = ["file_path", "name", "builtin"])]
+        all: bool,
     },
 
     /// 템플릿 삭제
     #[command(alias = "rm")]
     Remove {
-        /// 삭제할 템플릿 이름
+        /// 삭제할 템플릿 이름 (버전을 지정하지 않으면 모든 버전, `이름@`이면 버전 없는 파일만 삭제)
+        #[arg(add = ArgValueCandidates::new(template_candidates))]
+        template_name: TemplateSpec,
+    },
+
+    /// 새 템플릿 생성
+    New {
+        /// 생성할 템플릿 이름
+        template_name: TemplateSpec,
+
+        /// 내용을 복사할 기존 템플릿 이름
+        #[arg(long)]
+        from: Option<TemplateSpec>,
+    },
+
+    /// 템플릿을 편집기($EDITOR)로 편집
+    Edit {
+        /// 편집할 템플릿 이름
+        #[arg(add = ArgValueCandidates::new(template_candidates))]
+        template_name: TemplateSpec,
+    },
+
+    /// 템플릿 이름 변경 (모든 버전 이동, 참조하는 템플릿 갱신)
+    #[command(alias = "mv")]
+    Rename {
+        /// 기존 템플릿 이름
+        old_name: TemplateName,
+
+        /// 새 템플릿 이름
+        new_name: TemplateName,
+    },
+
+    /// 템플릿 복사 (모든 버전 복사)
+    #[command(alias = "cp")]
+    Copy {
+        /// 복사할 템플릿 이름
+        source: TemplateName,
+
+        /// 새 템플릿 이름
+        destination: TemplateName,
+    },
+
+    /// 템플릿 원본 내용과 메타데이터 보기
+    Show {
+        /// 조회할 템플릿 이름
+        #[arg(add = ArgValueCandidates::new(template_candidates))]
+        template_name: TemplateSpec,
+    },
+
+    /// 템플릿 렌더링 결과 미리보기 (include, 변수, 조건문 처리)
+    Preview {
+        /// 미리볼 템플릿 이름
+        #[arg(add = ArgValueCandidates::new(template_candidates))]
+        template_name: TemplateSpec,
+
+        /// 템플릿 변수 지정 (여러 번 사용 가능)
+        #[arg(long = "var", value_name = "KEY=VALUE")]
+        vars: Vec<String>,
+    },
+
+    /// 템플릿 검사 (프론트 매터, 변수, include, 조건문, 제목 구조, 크기)
+    Lint {
+        /// 검사할 템플릿 이름 (생략 시 설치된 모든 템플릿)
+        target: Option<TemplateSpec>,
+
+        /// 설치하지 않은 템플릿 파일 검사
+        #[arg(long, value_name = "PATH", conflicts_with = "target")]
+        file: Option<PathBuf>,
+    },
+
+    /// 내장 템플릿을 원래 내용으로 되돌리기 (수정 내용 diff 표시)
+    Reset {
+        /// 되돌릴 내장 템플릿 이름
+        #[arg(
+            value_name = "NAME",
+            ignore_case = true,
+            value_parser = PossibleValuesParser::new(embedded::builtin_names())
+        )]
         template_name: String,
     },
+
+    /// 이전 버전 템플릿 정리
+    Prune {
+        /// 정리할 템플릿 이름 (생략 시 모든 템플릿)
+        template_name: Option<TemplateName>,
+
+        /// 남겨둘 최신 버전 수 (1 이상)
+        #[arg(long, default_value_t = 1, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+        keep: usize,
+    },
+}
+
+/// 결과 출력 형식
+///
+/// 도움말의 값 설명도 번역되도록 `ValueEnum`을 직접 구현
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// 사람이 읽기 쉬운 형식
+    Text,
+    /// CI 등에서 사용할 JSON 형식
+    Json,
+}
+
+impl ValueEnum for OutputFormat {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[OutputFormat::Text, OutputFormat::Json]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            OutputFormat::Text => PossibleValue::new("text").help(t!("cli.format.text")),
+            OutputFormat::Json => PossibleValue::new("json").help(t!("cli.format.json")),
+        })
+    }
+}
+
+/// 자동 완성 스크립트를 생성할 셸
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum CompletionShell {
+    Bash,
+    Zsh,
+    Fish,
+    Powershell,
+}
+
+impl CompletionShell {
+    /// clap_complete의 셸 이름
+    pub fn name(self) -> &'static str {
+        match self {
+            CompletionShell::Bash => "bash",
+            CompletionShell::Zsh => "zsh",
+            CompletionShell::Fish => "fish",
+            CompletionShell::Powershell => "powershell",
+        }
+    }
+}
+
+/// 템플릿 이름 자동 완성 후보 (설치된 템플릿, 설정이 없으면 내장 템플릿)
+///
+/// 완성 중에는 오류를 출력할 수 없으므로 실패하면 후보 없음
+fn template_candidates() -> Vec<CompletionCandidate> {
+    let Ok(config) = config::load_or_builtin() else {
+        return Vec::new();
+    };
+    let Ok(templates) = template::list_templates(&config) else {
+        return Vec::new();
+    };
+
+    templates
+        .iter()
+        .map(|t| {
+            let description = template::get_template_content(&t.spec(), &config)
+                .ok()
+                .and_then(|content| {
+                    let (metadata, _) = render::split_front_matter(&content).ok()?;
+                    metadata.description
+                })
+                .filter(|d| !d.is_empty());
+            CompletionCandidate::new(t.name.as_str()).help(description.map(StyledStr::from))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::{Command, CommandFactory};
+    use init_ai::i18n;
+
+    #[test]
+    fn test_completion_shells() {
+        let shells = clap_complete::env::Shells::builtins();
+        for shell in CompletionShell::value_variants() {
+            assert!(shells.completer(shell.name()).is_some(), "{:?}", shell);
+        }
+    }
+
+    #[test]
+    fn test_catalog_covers_cli() {
+        fn check(command: &Command, prefix: &str) {
+            let about = format!("{}.about", prefix);
+            assert!(i18n::lookup(&about).is_some(), "{}", about);
+
+            for arg in command.get_arguments() {
+                let key = format!("{}.{}", prefix, arg.get_id());
+                assert!(i18n::lookup(&key).is_some(), "{}", key);
+            }
+            for sub in command.get_subcommands() {
+                check(sub, &format!("{}.{}", prefix, sub.get_name()));
+            }
+        }
+
+        check(&Cli::command(), "cli");
+    }
+
+    #[test]
+    fn test_rejects_traversal_names() {
+        let commands: [&[&str]; 11] = [
+            &["init"],
+            &["init", "--template"],
+            &["template", "install", "a.md", "--name"],
+            &["template", "remove"],
+            &["template", "new"],
+            &["template", "new", "Safe", "--from"],
+            &["template", "edit"],
+            &["template", "rename", "Safe"],
+            &["template", "copy", "Safe"],
+            &["template", "show"],
+            &["template", "prune"],
+        ];
+        for command in commands {
+            for attempt in ["../secret", "sub/../x", "x@../../secret"] {
+                let args = ["initai"].iter().chain(command).chain([&attempt]);
+                let result = Cli::try_parse_from(args);
+                let err = result.err().unwrap_or_else(|| panic!("{:?} {}", command, attempt));
+                assert_eq!(err.kind(), clap::error::ErrorKind::ValueValidation);
+            }
+        }
+
+        // 첫 번째 이름과 미리보기도 검증하고, 올바른 지정자는 그대로 허용
+        assert!(Cli::try_parse_from(["initai", "template", "rename", "../x", "Safe"]).is_err());
+        assert!(Cli::try_parse_from(["initai", "template", "preview", "../x"]).is_err());
+        assert!(Cli::try_parse_from(["initai", "template", "show", "Safe@1.0"]).is_ok());
+    }
 }
@@ -1,12 +1,32 @@
-use anyhow::{Context, Result};
+use anyhow::Context;
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use crate::backup;
+use crate::error::{Error, Result};
+use crate::i18n::{t, Lang};
+use crate::store::{EmbeddedStore, FsStore, LayeredStore, TemplateStore};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Config {
     pub template_path: PathBuf,
     pub default_template: String,
+    /// init 결과의 최대 추정 토큰 수 (템플릿의 max_tokens와 함께 지정되면 작은 값 사용)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<usize>,
+    /// 토큰 한도를 넘으면 경고 대신 init 실패
+    #[serde(default)]
+    pub fail_on_token_limit: bool,
+    /// 메시지 언어 (지정하지 않으면 LANG 환경 변수로 판별)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<Lang>,
+    /// init에서 사용할 템플릿 번역본 로케일 (지정하지 않으면 기본 파일)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    /// 설정 파일 없이 내장 템플릿만 사용하는지 여부 (저장하지 않음)
+    #[serde(skip)]
+    pub builtin_only: bool,
 }
 
 impl Config {
@@ -14,24 +34,58 @@ impl Config {
         Self {
             template_path,
             default_template: "Programming-Team".to_string(),
+            max_tokens: None,
+            fail_on_token_limit: false,
+            language: None,
+            locale: None,
+            builtin_only: false,
         }
     }
-}
 
-/// 설정 파일 경로 가져오기
-pub fn get_config_path() -> Result<PathBuf> {
-    let config_dir = dirs::config_dir()
-        .context("설정 디렉토리를 찾을 수 없습니다")?;
+    /// 설정 파일이 없을 때 사용하는 설정 (내장 템플릿만 사용, 템플릿 경로는 기본 경로)
+    pub fn builtin() -> Result<Self> {
+        Ok(Self {
+            builtin_only: true,
+            ..Self::new(default_template_path()?)
+        })
+    }
 
-    let init_ai_dir = config_dir.join("initai");
+    /// 템플릿 저장소
+    ///
+    /// 템플릿 경로에 설치된 템플릿이 같은 이름의 내장 템플릿을 가리고, 쓰기는 템플릿 경로에만
+    /// 작성. 내장 템플릿만 사용하는 경우 읽기 전용
+    pub fn store(&self) -> Box<dyn TemplateStore> {
+        if self.builtin_only {
+            Box::new(EmbeddedStore)
+        } else {
+            Box::new(
+                LayeredStore::new()
+                    .with(self.installed_store())
+                    .with(EmbeddedStore),
+            )
+        }
+    }
 
-    // 디렉토리가 없으면 생성
-    if !init_ai_dir.exists() {
-        fs::create_dir_all(&init_ai_dir)
-            .context("설정 디렉토리를 생성할 수 없습니다")?;
+    /// 템플릿 경로에 설치된 템플릿만 보는 저장소 (내장 템플릿 설치, 정리 등에 사용)
+    pub fn installed_store(&self) -> FsStore {
+        FsStore::new(&self.template_path)
     }
+}
 
-    Ok(init_ai_dir.join("config.toml"))
+/// 기본 템플릿 저장 경로 (설정 디렉토리 아래 `initai/templates`)
+pub fn default_template_path() -> Result<PathBuf> {
+    Ok(dirs::config_dir()
+        .context(t!("config.dir_not_found"))?
+        .join("initai")
+        .join("templates"))
+}
+
+/// 설정 파일 경로 가져오기
+pub fn get_config_path() -> Result<PathBuf> {
+    let config_dir = dirs::config_dir().context(t!("config.dir_not_found"))?;
+
+    // 디렉토리는 설정을 저장할 때 생성 (조회만 하는 명령은 아무것도 만들지 않음)
+    Ok(config_dir.join("initai").join("config.toml"))
 }
 
 /// 설정 파일 로드
@@ -39,27 +93,40 @@ pub fn load_config() -> Result<Config> {
     let config_path = get_config_path()?;
 
     if !config_path.exists() {
-        anyhow::bail!("설정 파일이 존재하지 않습니다. 템플릿 경로를 먼저 설정해주세요.");
+        return Err(Error::ConfigMissing);
     }
 
-    let content = fs::read_to_string(&config_path)
-        .context("설정 파일을 읽을 수 없습니다")?;
+    load_config_from(&config_path)
+}
 
-    let config: Config = toml::from_str(&content)
-        .context("설정 파일 형식이 올바르지 않습니다")?;
+/// 설정 파일 로드 (없으면 내장 템플릿만 사용하는 설정)
+pub fn load_or_builtin() -> Result<Config> {
+    if config_exists()? {
+        load_config()
+    } else {
+        Config::builtin()
+    }
+}
+
+/// 지정한 경로의 설정 파일 로드
+pub fn load_config_from(config_path: &Path) -> Result<Config> {
+    let content = fs::read_to_string(config_path).context(t!("config.read_failed"))?;
+
+    let config: Config = toml::from_str(&content).context(t!("config.invalid"))?;
 
     Ok(config)
 }
 
-/// 설정 파일 저장
+/// 설정 파일 저장 (저장 도중 중단되어도 기존 설정 파일이 손상되지 않도록 원자적으로 작성)
 pub fn save_config(config: &Config) -> Result<()> {
     let config_path = get_config_path()?;
+    if let Some(dir) = config_path.parent() {
+        fs::create_dir_all(dir).context(t!("config.dir_create_failed"))?;
+    }
 
-    let content = toml::to_string_pretty(config)
-        .context("설정을 직렬화할 수 없습니다")?;
+    let content = toml::to_string_pretty(config).context(t!("config.serialize_failed"))?;
 
-    fs::write(&config_path, content)
-        .context("설정 파일을 저장할 수 없습니다")?;
+    backup::write_atomic(&config_path, content).context(t!("config.save_failed"))?;
 
     Ok(())
 }
@@ -76,17 +143,31 @@ pub fn set_template_path(path: PathBuf) -> Result<()> {
     let absolute_path = if path.is_absolute() {
         path
     } else {
-        std::env::current_dir()?.join(path)
+        std::env::current_dir()
+            .map_err(|source| Error::Io {
+                path: PathBuf::from("."),
+                source,
+            })?
+            .join(path)
+    };
+
+    // 기존 설정이 있으면 다른 항목은 유지 (설정 파일이 없을 때만 기본값으로 새로 작성하고,
+    // 읽을 수 없거나 형식이 잘못된 설정 파일은 덮어쓰지 않음)
+    let config = match load_config() {
+        Ok(existing) => Config {
+            template_path: absolute_path.clone(),
+            ..existing
+        },
+        Err(Error::ConfigMissing) => Config::new(absolute_path.clone()),
+        Err(e) => return Err(e),
     };
 
     // 디렉토리가 없으면 생성
     if !absolute_path.exists() {
         fs::create_dir_all(&absolute_path)
-            .with_context(|| format!("디렉토리를 생성할 수 없습니다: {:?}", absolute_path))?;
+            .with_context(|| t!("config.create_dir_failed", absolute_path.display()))?;
     }
 
-    // 설정 저장
-    let config = Config::new(absolute_path);
     save_config(&config)?;
 
     Ok(())
@@ -105,6 +186,20 @@ mod tests {
         assert_eq!(config.default_template, "Programming-Team");
     }
 
+    #[test]
+    fn test_builtin_config() {
+        let config = Config {
+            builtin_only: true,
+            ..Config::new(PathBuf::from("/nonexistent"))
+        };
+        let store = config.store();
+        assert_eq!(store.list().unwrap().len(), crate::embedded::builtin_names().len());
+
+        // 저장하지 않으므로 다시 읽으면 일반 설정
+        let loaded: Config = toml::from_str(&toml::to_string(&config).unwrap()).unwrap();
+        assert!(!loaded.builtin_only);
+    }
+
     #[test]
     fn test_config_serialization() {
         let config = Config::new(PathBuf::from("/test/path"));
@@ -114,4 +209,16 @@ mod tests {
         assert_eq!(config.template_path, deserialized.template_path);
         assert_eq!(config.default_template, deserialized.default_template);
     }
+
+    #[test]
+    fn test_config_token_limit_defaults() {
+        // 토큰 한도 항목이 없는 기존 설정 파일도 읽을 수 있음
+        let config: Config =
+            toml::from_str("template_path = \"/test/path\"\ndefault_template = \"Programming-Team\"\n")
+                .unwrap();
+
+        assert_eq!(config.max_tokens, None);
+        assert!(!config.fail_on_token_limit);
+        assert_eq!(config.language, None);
+    }
 }
@@ -0,0 +1,525 @@
+use serde::Serialize;
+use std::fs::{self, OpenOptions};
+use std::path::Path;
+
+use crate::config::{self, Config};
+use crate::error::Result;
+use crate::i18n::t;
+use crate::init;
+use crate::lint::{self, Severity};
+use crate::lockfile::{self, Lockfile, TemplateState};
+use crate::store::TemplateStore;
+use crate::template;
+
+/// 진단 결과 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Warning,
+    Error,
+}
+
+/// 진단 항목
+#[derive(Debug, Clone, Serialize)]
+pub struct Finding {
+    /// 진단 결과 종류 (메시지 언어와 관계없이 고정, 예: `config.missing`, `file.modified`)
+    pub id: &'static str,
+    pub status: Status,
+    /// 확인한 대상의 표시 이름 (예: `설정 파일`, `템플릿 Programming-Team`)
+    pub check: String,
+    /// 확인한 템플릿 식별자나 파일 경로 (해당하는 경우)
+    pub target: Option<String>,
+    pub message: String,
+    /// 해결 방법
+    pub fix: Option<String>,
+}
+
+impl Finding {
+    fn ok(id: &'static str, check: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            id,
+            status: Status::Ok,
+            check: check.into(),
+            target: None,
+            message: message.into(),
+            fix: None,
+        }
+    }
+
+    fn warning(
+        id: &'static str,
+        check: impl Into<String>,
+        message: impl Into<String>,
+        fix: impl Into<String>,
+    ) -> Self {
+        Self {
+            status: Status::Warning,
+            fix: Some(fix.into()),
+            ..Self::ok(id, check, message)
+        }
+    }
+
+    fn error(
+        id: &'static str,
+        check: impl Into<String>,
+        message: impl Into<String>,
+        fix: impl Into<String>,
+    ) -> Self {
+        Self {
+            status: Status::Error,
+            fix: Some(fix.into()),
+            ..Self::ok(id, check, message)
+        }
+    }
+
+    /// 확인한 템플릿이나 파일 지정
+    fn target(mut self, target: impl Into<String>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+}
+
+/// 설정, 템플릿 디렉토리, 템플릿, 프로젝트(`root`) 상태 진단
+pub fn run(root: &Path) -> Result<Vec<Finding>> {
+    let config_path = config::get_config_path()?;
+    let mut findings = Vec::new();
+
+    let config = check_config(&config_path, &mut findings);
+    if let Some(config) = config.as_ref().filter(|c| !c.builtin_only) {
+        if check_template_dir(config, &mut findings) {
+            check_templates(config, &mut findings);
+        }
+    }
+    check_project(root, config.as_ref(), &mut findings);
+
+    Ok(findings)
+}
+
+/// 설정 파일 위치와 형식 확인
+///
+/// 설정 파일이 없으면 내장 템플릿만 사용하는 설정을 반환
+fn check_config(config_path: &Path, findings: &mut Vec<Finding>) -> Option<Config> {
+    let check = t!("doctor.check_config");
+
+    if !config_path.exists() {
+        findings.push(Finding::warning(
+            "config.missing",
+            check,
+            t!("doctor.config_missing", config_path.display()),
+            t!("doctor.config_missing_fix"),
+        ));
+        return Config::builtin().ok();
+    }
+
+    match config::load_config_from(config_path) {
+        Ok(config) => {
+            findings.push(Finding::ok("config.ok", check, config_path.display().to_string()));
+            Some(config)
+        }
+        Err(e) => {
+            findings.push(Finding::error(
+                "config.invalid",
+                check,
+                format!("{:#}: {}", e, config_path.display()),
+                t!("doctor.config_invalid_fix"),
+            ));
+            None
+        }
+    }
+}
+
+/// 템플릿 디렉토리 존재 여부와 권한 확인 (템플릿을 읽을 수 있으면 true)
+fn check_template_dir(config: &Config, findings: &mut Vec<Finding>) -> bool {
+    let check = t!("doctor.check_template_dir");
+    let dir = &config.template_path;
+
+    if !dir.exists() {
+        findings.push(Finding::error(
+            "template_dir.missing",
+            check,
+            t!("doctor.dir_missing", dir.display()),
+            t!("doctor.dir_missing_fix", dir.display()),
+        ));
+        return false;
+    }
+    if !dir.is_dir() {
+        findings.push(Finding::error(
+            "template_dir.not_dir",
+            check,
+            t!("doctor.not_dir", dir.display()),
+            t!("doctor.not_dir_fix"),
+        ));
+        return false;
+    }
+    if let Err(e) = fs::read_dir(dir) {
+        findings.push(Finding::error(
+            "template_dir.unreadable",
+            check,
+            t!("doctor.dir_unreadable", e, dir.display()),
+            t!("doctor.dir_unreadable_fix", dir.display()),
+        ));
+        return false;
+    }
+
+    // 쓰기 권한 확인 (임시 파일 생성 후 삭제)
+    let probe = dir.join(format!(".initai-doctor-{}", std::process::id()));
+    match OpenOptions::new().write(true).create_new(true).open(&probe) {
+        Ok(_) => {
+            let _ = fs::remove_file(&probe);
+            findings.push(Finding::ok("template_dir.ok", check, dir.display().to_string()));
+        }
+        Err(e) => findings.push(Finding::warning(
+            "template_dir.readonly",
+            check,
+            t!("doctor.dir_readonly", e, dir.display()),
+            t!("doctor.dir_readonly_fix", dir.display()),
+        )),
+    }
+
+    true
+}
+
+/// 설치된 모든 템플릿을 읽고 검사
+fn check_templates(config: &Config, findings: &mut Vec<Finding>) {
+    // 내장 템플릿은 함께 배포되므로 템플릿 경로에 설치된 템플릿만 검사
+    let templates = match config.installed_store().versions() {
+        Ok(templates) => templates,
+        Err(e) => {
+            findings.push(Finding::error(
+                "templates.unreadable",
+                t!("doctor.check_templates"),
+                format!("{:#}", e),
+                t!("doctor.templates_unreadable_fix"),
+            ));
+            return;
+        }
+    };
+
+    // 템플릿 디렉토리에 있지만 템플릿으로 읽지 않은 파일
+    match template::skipped_files(config) {
+        Ok(skipped) => {
+            for file in skipped {
+                let path = file.path.display().to_string();
+                findings.push(
+                    Finding::warning(
+                        "templates.skipped_file",
+                        t!("doctor.check_templates"),
+                        t!("doctor.skipped_file", path, file.reason),
+                        t!("doctor.skipped_file_fix"),
+                    )
+                    .target(path),
+                );
+            }
+        }
+        Err(e) => findings.push(Finding::error(
+            "templates.unreadable",
+            t!("doctor.check_templates"),
+            format!("{:#}", e),
+            t!("doctor.templates_unreadable_fix"),
+        )),
+    }
+
+    if templates.is_empty() {
+        findings.push(Finding::warning(
+            "templates.empty",
+            t!("doctor.check_templates"),
+            t!("doctor.no_templates"),
+            t!("doctor.no_templates_fix"),
+        ));
+        return;
+    }
+
+    for template in templates {
+        let check = t!("doctor.check_template", template.id());
+
+        let report = match lint::lint_template(&template.spec(), config) {
+            Ok(report) => report,
+            Err(e) => {
+                findings.push(
+                    Finding::error(
+                        "template.unreadable",
+                        check,
+                        format!("{:#}", e),
+                        t!("doctor.template_unreadable_fix", template.path.display()),
+                    )
+                    .target(template.id()),
+                );
+                continue;
+            }
+        };
+
+        let errors = report
+            .issues
+            .iter()
+            .filter(|i| i.severity == Severity::Error)
+            .count();
+        let warnings = report.issues.len() - errors;
+        let fix = t!("doctor.lint_fix", template.id());
+
+        let finding = if errors > 0 {
+            Finding::error(
+                "template.lint_errors",
+                check,
+                t!("doctor.lint_errors", errors, warnings),
+                fix,
+            )
+        } else if warnings > 0 {
+            Finding::warning(
+                "template.lint_warnings",
+                check,
+                t!("doctor.lint_warnings", warnings),
+                fix,
+            )
+        } else {
+            Finding::ok("template.ok", check, t!("doctor.healthy"))
+        };
+        findings.push(finding.target(template.id()));
+    }
+}
+
+/// 프로젝트 락파일과 생성 파일 상태 확인
+fn check_project(root: &Path, config: Option<&Config>, findings: &mut Vec<Finding>) {
+    let check = t!("doctor.check_lockfile");
+
+    let lock = match Lockfile::load(root) {
+        Ok(lock) => lock,
+        Err(e) => {
+            findings.push(Finding::error(
+                "lockfile.invalid",
+                check,
+                format!("{:#}", e),
+                t!("doctor.lockfile_invalid_fix", lockfile::LOCKFILE_NAME),
+            ));
+            return;
+        }
+    };
+
+    let Some(lock) = lock else {
+        if root.join(init::TEAM_RULES_FILE).exists() {
+            findings.push(Finding::warning(
+                "lockfile.missing",
+                check,
+                t!("doctor.lockfile_missing", init::TEAM_RULES_FILE),
+                t!("doctor.lockfile_missing_fix"),
+            ));
+        } else {
+            findings.push(Finding::ok(
+                "lockfile.not_initialized",
+                check,
+                t!("doctor.not_initialized"),
+            ));
+        }
+        return;
+    };
+
+    findings.push(Finding::ok(
+        "lockfile.ok",
+        check,
+        t!("doctor.managed_files", lock.files.len()),
+    ));
+
+    for entry in &lock.files {
+        let check = entry.path.display().to_string();
+        let reinit = t!("doctor.reinit_fix", entry.template);
+        let mut push = |finding: Finding| findings.push(finding.target(check.clone()));
+
+        if !root.join(&entry.path).exists() {
+            push(Finding::error(
+                "file.missing",
+                check.clone(),
+                t!("doctor.file_missing"),
+                t!("doctor.file_missing_fix", reinit),
+            ));
+            continue;
+        }
+
+        let Some(config) = config else {
+            continue;
+        };
+
+        let status = lockfile::inspect(root, entry, config);
+        match &status.template {
+            TemplateState::Missing => push(Finding::error(
+                "file.template_missing",
+                check.clone(),
+                t!("doctor.template_missing", entry.template),
+                t!("doctor.template_missing_fix"),
+            )),
+            TemplateState::Invalid(e) => push(Finding::error(
+                "file.template_invalid",
+                check.clone(),
+                t!("doctor.template_invalid", entry.template, e),
+                t!("doctor.template_invalid_fix", entry.template),
+            )),
+            TemplateState::Changed => push(Finding::warning(
+                "file.template_changed",
+                check.clone(),
+                t!("doctor.template_changed", entry.template),
+                reinit.clone(),
+            )),
+            TemplateState::Unchanged => {}
+        }
+
+        if let Some(latest) = &status.newer_version {
+            push(Finding::warning(
+                "file.newer_version",
+                check.clone(),
+                t!("doctor.newer_version", latest),
+                t!("doctor.newer_version_fix", latest),
+            ));
+        }
+
+        if status.modified {
+            push(Finding::warning(
+                "file.modified",
+                check.clone(),
+                t!("doctor.file_modified"),
+                t!("doctor.file_modified_fix"),
+            ));
+        }
+
+        if status.template == TemplateState::Unchanged && !status.modified {
+            push(Finding::ok(
+                "file.up_to_date",
+                check.clone(),
+                t!("doctor.up_to_date", entry.template),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::name::TemplateSpec;
+    use std::collections::BTreeMap;
+    use tempfile::tempdir;
+
+    fn statuses(findings: &[Finding]) -> Vec<Status> {
+        findings.iter().map(|f| f.status).collect()
+    }
+
+    #[test]
+    fn test_finding_json() {
+        let finding = Finding::warning("config.missing", "설정 파일", "없음", "initai config");
+        assert_eq!(
+            serde_json::to_value(&finding).unwrap(),
+            serde_json::json!({
+                "id": "config.missing",
+                "status": "warning",
+                "check": "설정 파일",
+                "target": null,
+                "message": "없음",
+                "fix": "initai config",
+            })
+        );
+    }
+
+    #[test]
+    fn test_check_config() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("config.toml");
+
+        let mut findings = Vec::new();
+        assert!(check_config(&path, &mut findings).is_some_and(|c| c.builtin_only));
+
+        fs::write(&path, "template_path = ").unwrap();
+        assert!(check_config(&path, &mut findings).is_none());
+
+        fs::write(&path, toml::to_string(&Config::new(temp_dir.path().into())).unwrap()).unwrap();
+        assert!(check_config(&path, &mut findings).is_some());
+
+        assert_eq!(statuses(&findings), vec![Status::Warning, Status::Error, Status::Ok]);
+        assert!(findings.iter().all(|f| f.status == Status::Ok || f.fix.is_some()));
+    }
+
+    #[test]
+    fn test_check_templates() {
+        let temp_dir = tempdir().unwrap();
+        let config = Config::new(temp_dir.path().join("templates"));
+
+        let mut findings = Vec::new();
+        assert!(!check_template_dir(&config, &mut findings));
+
+        fs::create_dir(&config.template_path).unwrap();
+        fs::write(config.template_path.join("Good.md"), "# Good\n").unwrap();
+        fs::write(config.template_path.join("Bad.md"), "{{#if x}}\n").unwrap();
+        assert!(check_template_dir(&config, &mut findings));
+        check_templates(&config, &mut findings);
+
+        assert_eq!(
+            statuses(&findings),
+            vec![Status::Error, Status::Ok, Status::Error, Status::Ok]
+        );
+        assert_eq!(findings[2].id, "template.lint_errors");
+        assert_eq!(findings[2].target.as_deref(), Some("Bad"));
+
+        // 템플릿으로 읽지 않은 파일과 기본 파일이 없는 번역본은 경고
+        fs::write(config.template_path.join("bad:name.md"), "# Bad\n").unwrap();
+        fs::write(config.template_path.join("Missing.ko.md"), "# 없음\n").unwrap();
+        let mut findings = Vec::new();
+        check_templates(&config, &mut findings);
+        let skipped: Vec<&Finding> = findings
+            .iter()
+            .filter(|f| f.id == "templates.skipped_file")
+            .collect();
+        assert_eq!(skipped.len(), 2);
+        assert!(skipped.iter().all(|f| f.status == Status::Warning));
+        let target = config.template_path.join("Missing.ko.md");
+        assert_eq!(skipped[0].target.as_deref(), Some(target.to_str().unwrap()));
+    }
+
+    #[test]
+    fn test_check_project() {
+        let root = tempdir().unwrap();
+        let template_dir = tempdir().unwrap();
+        let config = Config::new(template_dir.path().to_path_buf());
+        fs::write(template_dir.path().join("Rules.md"), "# Rules\n").unwrap();
+
+        // 락파일 없이 규칙 파일만 있음
+        fs::create_dir(root.path().join("rules")).unwrap();
+        fs::write(root.path().join(init::TEAM_RULES_FILE), "# Manual\n").unwrap();
+        let mut findings = Vec::new();
+        check_project(root.path(), Some(&config), &mut findings);
+        assert_eq!(statuses(&findings), vec![Status::Warning]);
+
+        // init 직후에는 최신 상태
+        let plan = init::plan_init(
+            root.path(),
+            &TemplateSpec::parse("Rules").unwrap(),
+            None,
+            &BTreeMap::new(),
+            &config,
+        )
+        .unwrap();
+        init::apply_plan(&plan).unwrap();
+        let mut findings = Vec::new();
+        check_project(root.path(), Some(&config), &mut findings);
+        assert_eq!(statuses(&findings), vec![Status::Ok, Status::Ok]);
+
+        // 템플릿이 바뀌고 파일도 직접 수정됨
+        fs::write(template_dir.path().join("Rules.md"), "# Rules v2\n").unwrap();
+        fs::write(root.path().join(init::TEAM_RULES_FILE), "# Edited\n").unwrap();
+        let mut findings = Vec::new();
+        check_project(root.path(), Some(&config), &mut findings);
+        assert_eq!(
+            statuses(&findings),
+            vec![Status::Ok, Status::Warning, Status::Warning]
+        );
+        let ids: Vec<&str> = findings.iter().map(|f| f.id).collect();
+        assert_eq!(ids, vec!["lockfile.ok", "file.template_changed", "file.modified"]);
+        assert_eq!(findings[1].target.as_deref(), Some("rules/TEAM_RULES.md"));
+
+        // 템플릿 삭제, 규칙 파일 삭제
+        fs::remove_file(template_dir.path().join("Rules.md")).unwrap();
+        let mut findings = Vec::new();
+        check_project(root.path(), Some(&config), &mut findings);
+        assert_eq!(statuses(&findings), vec![Status::Ok, Status::Error, Status::Warning]);
+
+        fs::remove_file(root.path().join(init::TEAM_RULES_FILE)).unwrap();
+        let mut findings = Vec::new();
+        check_project(root.path(), Some(&config), &mut findings);
+        assert_eq!(statuses(&findings), vec![Status::Ok, Status::Error]);
+    }
+}
@@ -1,10 +1,41 @@
 /// Programming-Team 템플릿 (기본 템플릿)
 pub const PROGRAMMING_TEAM: &str = include_str!("../templates/programming-team.md");
 
+/// Programming-Team 템플릿 영어 번역본
+pub const PROGRAMMING_TEAM_EN: &str = include_str!("../templates/programming-team.en.md");
+
 /// 기본 템플릿 이름
 pub const DEFAULT_TEMPLATE_NAME: &str = "Programming-Team";
 
-/// 사용 가능한 내장 템플릿 목록
-pub fn get_embedded_templates() -> Vec<(&'static str, &'static str)> {
-    vec![(DEFAULT_TEMPLATE_NAME, PROGRAMMING_TEAM)]
+/// 내장 템플릿 목록 (이름, 내용)
+const TEMPLATES: &[(&str, &str)] = &[
+    (DEFAULT_TEMPLATE_NAME, PROGRAMMING_TEAM),
+    ("Solo-Developer", include_str!("../templates/solo-developer.md")),
+    ("Code-Review", include_str!("../templates/code-review.md")),
+    ("Data-Science", include_str!("../templates/data-science.md")),
+    ("Frontend", include_str!("../templates/frontend.md")),
+    ("Security-Review", include_str!("../templates/security-review.md")),
+    ("Docs-Writing", include_str!("../templates/docs-writing.md")),
+    ("OSS-Maintainer", include_str!("../templates/oss-maintainer.md")),
+];
+
+/// 내장 템플릿 번역본 목록 (이름, 로케일, 내용)
+const TRANSLATIONS: &[(&str, &str, &str)] = &[(DEFAULT_TEMPLATE_NAME, "en", PROGRAMMING_TEAM_EN)];
+
+/// 내장 템플릿 번역본 목록 (이름, 로케일, 내용)
+pub fn get_embedded_translations() -> Vec<(&'static str, &'static str, &'static str)> {
+    TRANSLATIONS.to_vec()
+}
+
+/// 내장 템플릿 이름 목록
+pub fn builtin_names() -> Vec<&'static str> {
+    TEMPLATES.iter().map(|(name, _)| *name).collect()
+}
+
+/// 이름으로 내장 템플릿 찾기 (대소문자 구분 없음)
+pub fn find_builtin(name: &str) -> Option<(&'static str, &'static str)> {
+    TEMPLATES
+        .iter()
+        .find(|(builtin, _)| builtin.eq_ignore_ascii_case(name))
+        .copied()
 }
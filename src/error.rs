@@ -0,0 +1,184 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+use crate::i18n::t;
+use crate::name::TemplateSpec;
+
+/// 라이브러리 결과 타입
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// 종료 코드: 분류되지 않은 오류
+pub const EXIT_FAILURE: i32 = 1;
+/// 종료 코드: 잘못된 명령어 또는 옵션 (clap)
+pub const EXIT_USAGE: i32 = 2;
+/// 종료 코드: 설정 파일 없음
+pub const EXIT_CONFIG_MISSING: i32 = 3;
+/// 종료 코드: 템플릿을 찾을 수 없음
+pub const EXIT_TEMPLATE_NOT_FOUND: i32 = 4;
+/// 종료 코드: 검증 또는 검사 실패
+pub const EXIT_VALIDATION: i32 = 5;
+/// 종료 코드: 이미 존재하는 파일이나 템플릿과 충돌
+pub const EXIT_CONFLICT: i32 = 6;
+/// 종료 코드: 사용자가 취소함
+pub const EXIT_CANCELLED: i32 = 7;
+/// 종료 코드: 파일 입출력 실패
+pub const EXIT_IO: i32 = 8;
+/// 종료 코드: 네트워크 오류 (원격 템플릿 저장소 등)
+pub const EXIT_NETWORK: i32 = 9;
+/// 종료 코드: 읽기 전용 템플릿 저장소(내장 템플릿 등)에 쓰기 시도
+pub const EXIT_READ_ONLY: i32 = 10;
+
+/// 라이브러리 오류
+#[derive(Debug)]
+pub enum Error {
+    /// 설정 파일이 없음
+    ConfigMissing,
+    /// 템플릿이 설치되어 있지 않음
+    TemplateNotFound(TemplateSpec),
+    /// 입력이나 템플릿이 올바르지 않음 (이름, 변수, 템플릿 검사, 토큰 한도 등)
+    Validation(String),
+    /// 이미 존재하는 파일이나 템플릿과 충돌
+    Conflict(String),
+    /// 사용자가 확인 프롬프트에서 취소함
+    Cancelled,
+    /// 읽기 전용 저장소에 쓰기 시도
+    ReadOnly,
+    /// 템플릿 렌더링 실패 (문법 오류, 정의되지 않은 변수, 순환 참조 등)
+    Render {
+        template: String,
+        source: anyhow::Error,
+    },
+    /// 파일 입출력 실패
+    Io { path: PathBuf, source: io::Error },
+    /// 네트워크 요청 실패 (원격 저장소를 구현하는 `TemplateStore`용)
+    Network { url: String, source: anyhow::Error },
+    /// 그 밖의 오류
+    Other(anyhow::Error),
+}
+
+impl Error {
+    /// 오류 종류에 대응하는 프로세스 종료 코드
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::ConfigMissing => EXIT_CONFIG_MISSING,
+            Error::TemplateNotFound(_) => EXIT_TEMPLATE_NOT_FOUND,
+            Error::Validation(_) | Error::Render { .. } => EXIT_VALIDATION,
+            Error::Conflict(_) => EXIT_CONFLICT,
+            Error::Cancelled => EXIT_CANCELLED,
+            Error::ReadOnly => EXIT_READ_ONLY,
+            Error::Io { .. } => EXIT_IO,
+            Error::Network { .. } => EXIT_NETWORK,
+            Error::Other(e) => exit_code(e),
+        }
+    }
+}
+
+/// 오류 체인에서 가장 바깥쪽의 분류된 오류를 찾아 종료 코드 결정
+///
+/// `Error`가 없으면 체인에 `io::Error`가 있는 경우 입출력 오류로 분류
+pub fn exit_code(error: &anyhow::Error) -> i32 {
+    if let Some(e) = error.chain().find_map(|e| e.downcast_ref::<Error>()) {
+        return e.exit_code();
+    }
+    if error.chain().any(|e| e.is::<io::Error>()) {
+        return EXIT_IO;
+    }
+    EXIT_FAILURE
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::ConfigMissing => write!(f, "{}", t!("config.missing")),
+            Error::TemplateNotFound(spec) => write!(f, "{}", t!("template.not_found", spec)),
+            Error::Validation(message) | Error::Conflict(message) => write!(f, "{}", message),
+            Error::Cancelled => write!(f, "{}", t!("common.cancelled")),
+            Error::ReadOnly => write!(f, "{}", t!("error.read_only")),
+            Error::Render { template, .. } => write!(f, "{}", t!("error.render", template)),
+            Error::Io { path, .. } => write!(f, "{}", t!("error.io", path.display())),
+            Error::Network { url, .. } => write!(f, "{}", t!("error.network", url)),
+            // `{:#}`이면 원인 체인까지 표시
+            Error::Other(e) if f.alternate() => write!(f, "{:#}", e),
+            Error::Other(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Render { source, .. } | Error::Network { source, .. } => Some(source.as_ref()),
+            Error::Io { source, .. } => Some(source),
+            Error::Other(e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<anyhow::Error> for Error {
+    /// 가장 바깥쪽이 `Error`이면 그대로 꺼내고, 아니면 원인 체인째로 `Other`로 감쌈
+    fn from(e: anyhow::Error) -> Self {
+        e.downcast::<Error>().unwrap_or_else(Error::Other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn test_exit_codes() {
+        let spec = TemplateSpec::parse("Missing").unwrap();
+        let errors = [
+            Error::ConfigMissing,
+            Error::TemplateNotFound(spec),
+            Error::Validation("invalid".to_string()),
+            Error::Conflict("exists".to_string()),
+            Error::Cancelled,
+            Error::Io {
+                path: PathBuf::from("a.md"),
+                source: io::Error::other("io"),
+            },
+            Error::Network {
+                url: "https://example.com".to_string(),
+                source: anyhow::anyhow!("timeout"),
+            },
+            Error::ReadOnly,
+        ];
+        let mut codes: Vec<i32> = errors.iter().map(Error::exit_code).collect();
+        codes.extend([EXIT_FAILURE, EXIT_USAGE]);
+        let count = codes.len();
+        codes.sort();
+        codes.dedup();
+        assert_eq!(codes.len(), count);
+
+        // context로 감싸도 안쪽의 분류된 오류 사용
+        let wrapped = anyhow::Error::new(Error::Cancelled).context("outer");
+        assert_eq!(exit_code(&wrapped), EXIT_CANCELLED);
+
+        let io = std::fs::read("/nonexistent/initai").context("read failed");
+        assert_eq!(exit_code(&io.unwrap_err()), EXIT_IO);
+        assert_eq!(exit_code(&anyhow::anyhow!("plain")), EXIT_FAILURE);
+
+        // Other로 감싼 오류도 안쪽 분류를 따름
+        let other = Error::Other(anyhow::Error::new(Error::ConfigMissing).context("load"));
+        assert_eq!(other.exit_code(), EXIT_CONFIG_MISSING);
+
+        // anyhow 오류로 전달된 Error는 원래 종류로 되돌림
+        let unwrapped = Error::from(anyhow::Error::new(Error::Cancelled));
+        assert!(matches!(unwrapped, Error::Cancelled));
+    }
+
+    #[test]
+    fn test_other_source() {
+        use std::error::Error as _;
+
+        // Other의 원인은 감싼 오류 자체 (그 아래 원인 체인도 이어짐)
+        let other = Error::Other(anyhow::anyhow!("inner").context("outer"));
+        let source = other.source().unwrap();
+        assert_eq!(source.to_string(), "outer");
+        assert_eq!(source.source().unwrap().to_string(), "inner");
+    }
+}
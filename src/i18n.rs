@@ -0,0 +1,320 @@
+use clap::{Command, ValueEnum};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Write};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::messages::MESSAGES;
+
+/// 메시지 언어
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Lang {
+    #[default]
+    En,
+    Ko,
+}
+
+impl fmt::Display for Lang {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Lang::En => write!(f, "en"),
+            Lang::Ko => write!(f, "ko"),
+        }
+    }
+}
+
+/// 현재 메시지 언어 (`Lang as u8`)
+static CURRENT: AtomicU8 = AtomicU8::new(Lang::En as u8);
+
+/// 메시지 언어 설정
+pub fn set_language(lang: Lang) {
+    CURRENT.store(lang as u8, Ordering::Relaxed);
+}
+
+/// 현재 메시지 언어
+pub fn current() -> Lang {
+    if CURRENT.load(Ordering::Relaxed) == Lang::Ko as u8 {
+        Lang::Ko
+    } else {
+        Lang::En
+    }
+}
+
+/// 로케일 값(`ko_KR.UTF-8` 등)에서 언어 판별
+///
+/// `C`, `POSIX`는 언어를 나타내지 않으므로 None(기본 언어인 영어 사용), 한국어가 아닌 로케일은 영어
+pub fn from_locale(value: &str) -> Option<Lang> {
+    let language = value.split(['_', '.', '@', '-']).next().unwrap_or_default();
+    match language {
+        "" | "C" | "POSIX" => None,
+        "ko" => Some(Lang::Ko),
+        _ => Some(Lang::En),
+    }
+}
+
+/// 환경 변수에서 언어 판별 (`LC_ALL`, `LC_MESSAGES`, `LANG` 순으로 처음 설정된 값 사용)
+pub fn from_env() -> Option<Lang> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .find_map(|name| std::env::var(name).ok().filter(|value| !value.is_empty()))
+        .and_then(|value| from_locale(&value))
+}
+
+/// 명령줄 인수에서 `--lang` 값 찾기
+///
+/// 도움말과 인수 오류는 파싱 도중 출력되므로 파싱 전에 언어를 정할 때 사용
+pub fn from_args(args: impl IntoIterator<Item = String>) -> Option<Lang> {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let value = match arg.as_str() {
+            "--" => return None,
+            "--lang" => args.next()?,
+            _ => match arg.strip_prefix("--lang=") {
+                Some(value) => value.to_string(),
+                None => continue,
+            },
+        };
+        return Lang::from_str(&value, true).ok();
+    }
+    None
+}
+
+/// 카탈로그에서 현재 언어의 메시지 찾기
+pub fn lookup(key: &str) -> Option<&'static str> {
+    MESSAGES
+        .iter()
+        .find(|(k, _, _)| *k == key)
+        .map(|(_, en, ko)| match current() {
+            Lang::En => *en,
+            Lang::Ko => *ko,
+        })
+}
+
+/// 현재 언어의 메시지 (카탈로그에 없으면 키 그대로)
+pub fn text(key: &'static str) -> &'static str {
+    debug_assert!(lookup(key).is_some(), "메시지 카탈로그에 없는 키: {}", key);
+    lookup(key).unwrap_or(key)
+}
+
+/// 현재 언어의 메시지에 인수 대입
+pub fn format(key: &'static str, args: &[&dyn fmt::Display]) -> String {
+    substitute(text(key), args)
+}
+
+/// `{}`는 차례로, `{0}`, `{1}` 등은 위치를 지정해서 인수 대입
+fn substitute(message: &str, args: &[&dyn fmt::Display]) -> String {
+    let mut result = String::with_capacity(message.len());
+    let mut rest = message;
+    let mut next = 0;
+
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+
+        match after.find('}') {
+            Some(end) if after[..end].bytes().all(|b| b.is_ascii_digit()) => {
+                let index = if end == 0 {
+                    next += 1;
+                    next - 1
+                } else {
+                    after[..end].parse().unwrap_or(usize::MAX)
+                };
+                if let Some(arg) = args.get(index) {
+                    let _ = write!(result, "{}", arg);
+                }
+                rest = &after[end + 1..];
+            }
+            _ => {
+                result.push('{');
+                rest = after;
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// 현재 언어의 메시지 (`t!("키")` 또는 `t!("키", 인수...)`)
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __t {
+    ($key:expr) => {
+        $crate::i18n::text($key)
+    };
+    ($key:expr, $($arg:expr),+ $(,)?) => {
+        $crate::i18n::format($key, &[$(&$arg as &dyn ::std::fmt::Display),+])
+    };
+}
+pub use crate::__t as t;
+
+/// clap 명령 정의의 설명과 도움말을 현재 언어로 교체
+///
+/// 명령은 `cli.<하위 명령>.about`, 인수는 `cli.<하위 명령>.<인수 ID>` 키 사용.
+/// `Usage:` 등 clap이 만드는 제목과 인수 오류 메시지는 교체할 수 없어 영어 그대로 표시
+pub fn localize_command(command: Command) -> Command {
+    localize(command, "cli")
+}
+
+fn localize(mut command: Command, prefix: &str) -> Command {
+    if let Some(about) = lookup(&format!("{}.about", prefix)) {
+        command = command.about(about);
+    }
+
+    let args: Vec<String> = command
+        .get_arguments()
+        .map(|arg| arg.get_id().to_string())
+        .collect();
+    for id in args {
+        if let Some(help) = lookup(&format!("{}.{}", prefix, id)) {
+            command = command.mut_arg(id, |arg| arg.help(help));
+        }
+    }
+
+    let subcommands: Vec<String> = command
+        .get_subcommands()
+        .map(|sub| sub.get_name().to_string())
+        .collect();
+    for name in subcommands {
+        let key = format!("{}.{}", prefix, name);
+        command = command.mut_subcommand(name, |sub| localize(sub, &key));
+    }
+
+    command
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    /// 메시지에 사용된 인수 위치 목록
+    fn placeholders(message: &str) -> BTreeSet<usize> {
+        let mut result = BTreeSet::new();
+        let mut next = 0;
+        let mut rest = message;
+        while let Some(start) = rest.find('{') {
+            let after = &rest[start + 1..];
+            match after.find('}') {
+                Some(0) => {
+                    result.insert(next);
+                    next += 1;
+                    rest = &after[1..];
+                }
+                Some(end) if after[..end].bytes().all(|b| b.is_ascii_digit()) => {
+                    result.insert(after[..end].parse().unwrap());
+                    rest = &after[end + 1..];
+                }
+                _ => rest = after,
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn test_catalog_consistency() {
+        let mut keys = BTreeSet::new();
+        for (key, en, ko) in MESSAGES {
+            assert!(keys.insert(*key), "중복된 키: {}", key);
+            assert!(!en.is_empty() && !ko.is_empty(), "빈 메시지: {}", key);
+            assert_eq!(placeholders(en), placeholders(ko), "인수 불일치: {}", key);
+        }
+    }
+
+    /// 줄에 있는 문자열 리터럴 (이스케이프만 처리)
+    fn string_literals(line: &str) -> Vec<&str> {
+        let mut literals = Vec::new();
+        let mut start = None;
+        let mut escaped = false;
+        for (index, c) in line.char_indices() {
+            match (start, c) {
+                (Some(_), _) if escaped => escaped = false,
+                (Some(_), '\\') => escaped = true,
+                (Some(s), '"') => {
+                    literals.push(&line[s..index]);
+                    start = None;
+                }
+                (None, '"') => start = Some(index + 1),
+                _ => {}
+            }
+        }
+        literals
+    }
+
+    #[test]
+    fn test_catalog_covers_sources() {
+        // 카탈로그를 거치지 않아도 되는 줄 (파일 내용, 내부 불변식)
+        const ALLOWED: &[&str] = &["debug_assert!(", ".expect("];
+        let is_hangul = |c: char| ('\u{AC00}'..='\u{D7A3}').contains(&c);
+
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+        let mut checked = 0;
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_none_or(|e| e != "rs") || path.ends_with("messages.rs") {
+                continue;
+            }
+            let source = std::fs::read_to_string(&path).unwrap();
+            checked += 1;
+
+            // 테스트 밖의 한국어 문자열은 모두 카탈로그에 있어야 함
+            let code = source.split("#[cfg(test)]").next().unwrap();
+            for (number, line) in code.lines().enumerate() {
+                let code = line.trim_start();
+                if code.starts_with("//") || ALLOWED.iter().any(|a| code.contains(a)) {
+                    continue;
+                }
+                assert!(
+                    !string_literals(code).iter().any(|l| l.chars().any(is_hangul)),
+                    "카탈로그를 거치지 않은 메시지: {}:{}",
+                    path.display(),
+                    number + 1
+                );
+            }
+
+            for (index, _) in source.match_indices("t!(\"") {
+                // format!( 등 다른 매크로와 주석 제외
+                let before = source[..index].chars().next_back();
+                if before.is_some_and(|c| c.is_alphanumeric() || c == '_') {
+                    continue;
+                }
+                let line_start = source[..index].rfind('\n').map_or(0, |i| i + 1);
+                if source[line_start..index].trim_start().starts_with("//") {
+                    continue;
+                }
+                let part = &source[index + 4..];
+                let key = &part[..part.find('"').unwrap()];
+                assert!(
+                    MESSAGES.iter().any(|(k, _, _)| *k == key),
+                    "메시지 카탈로그에 없는 키: {}",
+                    key
+                );
+            }
+        }
+        assert!(checked > 10, "{}", dir);
+    }
+
+    #[test]
+    fn test_substitute() {
+        assert_eq!(substitute("{} -> {}", &[&"a", &1]), "a -> 1");
+        assert_eq!(substitute("{1} <- {0}", &[&"a", &"b"]), "b <- a");
+        assert_eq!(substitute("{{ team }} {x}", &[&"a"]), "{{ team }} {x}");
+    }
+
+    #[test]
+    fn test_language_detection() {
+        assert_eq!(from_locale("ko_KR.UTF-8"), Some(Lang::Ko));
+        assert_eq!(from_locale("en_US.UTF-8"), Some(Lang::En));
+        assert_eq!(from_locale("de"), Some(Lang::En));
+        assert_eq!(from_locale("C.UTF-8"), None);
+        assert_eq!(from_locale("POSIX"), None);
+        // 언어를 알 수 없으면 영어
+        assert_eq!(Lang::default(), Lang::En);
+
+        let args = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(from_args(args(&["list", "--lang", "en"])), Some(Lang::En));
+        assert_eq!(from_args(args(&["--lang=ko", "list"])), Some(Lang::Ko));
+        assert_eq!(from_args(args(&["list"])), None);
+        assert_eq!(from_args(args(&["--", "--lang", "en"])), None);
+    }
+}
@@ -1,112 +1,733 @@
-use anyhow::{Context, Result};
+use anyhow::Context;
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use crate::backup::{self, Backup};
 use crate::config::Config;
+use crate::error::{Error, Result};
+use crate::i18n::t;
+use crate::lockfile::{self, LockedFile, Lockfile};
+use crate::name::TemplateSpec;
+use crate::render::{self, Renderer};
+use crate::store::TemplateStore;
 use crate::template;
+use crate::tokens::{self, TokenEstimate};
 
-/// 프로젝트 초기화
-pub fn init_project(template_name: &str, config: &Config) -> Result<()> {
-    // rules 디렉토리 생성
-    create_rules_directory()?;
+/// 규칙 파일 경로 (프로젝트 루트 기준)
+pub const TEAM_RULES_FILE: &str = "rules/TEAM_RULES.md";
 
-    // 템플릿 내용 가져오기
-    let content = template::get_template_content(template_name, config)
-        .with_context(|| format!("템플릿 '{}'을(를) 찾을 수 없습니다", template_name))?;
+/// 초기화로 작성하는 파일 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileKind {
+    /// 템플릿으로 생성한 규칙 파일
+    Output,
+    /// 프로젝트 락파일 (덮어쓰기 확인과 백업 없이 갱신)
+    Lockfile,
+}
 
-    // TEAM_RULES.md 작성
-    write_team_rules(&content)?;
+/// 초기화 중 수행할 파일 작업
+#[derive(Debug, Clone)]
+pub struct FileOperation {
+    /// 프로젝트 루트 기준 경로
+    pub path: PathBuf,
+    pub content: String,
+    /// 계획 시점에 이미 존재해서 덮어쓰게 되는 파일인지 여부
+    pub overwrite: bool,
+    pub kind: FileKind,
+}
 
-    Ok(())
+/// 초기화 계획 (디스크를 건드리기 전에 모든 쓰기 작업을 미리 결정)
+#[derive(Debug, Clone)]
+pub struct InitPlan {
+    pub root: PathBuf,
+    pub operations: Vec<FileOperation>,
+    /// 적용은 가능하지만 사용자에게 알려야 하는 문제 (토큰 한도 초과 등)
+    pub warnings: Vec<String>,
+    /// 락파일에 고정한 템플릿 (`이름@버전` 또는 버전 없는 파일은 `이름@`)
+    pub template: String,
+    /// 사용한 번역본 로케일 (기본 파일을 사용했으면 None)
+    pub locale: Option<String>,
 }
 
-/// rules 디렉토리 생성
-fn create_rules_directory() -> Result<()> {
-    let rules_dir = PathBuf::from("rules");
+impl InitPlan {
+    /// 기존 파일을 덮어쓰게 되는 작업 목록 (락파일 제외)
+    pub fn conflicts(&self) -> Vec<&FileOperation> {
+        self.operations
+            .iter()
+            .filter(|op| op.overwrite && op.kind == FileKind::Output)
+            .collect()
+    }
 
-    if !rules_dir.exists() {
-        fs::create_dir(&rules_dir)
-            .context("rules 디렉토리를 생성할 수 없습니다")?;
+    /// 생성되는 규칙 파일 작업 목록
+    pub fn outputs(&self) -> impl Iterator<Item = &FileOperation> {
+        self.operations.iter().filter(|op| op.kind == FileKind::Output)
     }
+}
 
-    Ok(())
+/// 다시 생성한 결과와 현재 파일이 다른 대상
+#[derive(Debug, Clone)]
+pub struct Drift {
+    /// 프로젝트 루트 기준 경로
+    pub path: PathBuf,
+    /// 지금 생성하면 작성될 내용
+    pub expected: String,
+    /// 현재 파일 내용 (파일이 없으면 None)
+    pub actual: Option<String>,
+}
+
+/// 적용 중 완료된 작업 (롤백용)
+enum AppliedStep {
+    CreatedDir(PathBuf),
+    CreatedFile(PathBuf),
+    /// 기존 파일을 덮어씀 (되돌리면 원래 내용으로 복원하고 이번에 만든 백업 삭제)
+    ReplacedFile {
+        path: PathBuf,
+        original: Vec<u8>,
+        backup: Option<Backup>,
+    },
+}
+
+/// 프로젝트 초기화 (현재 디렉토리 기준)
+///
+/// 모든 쓰기 작업을 먼저 계획하고, 기존 파일을 덮어쓰게 되면 `confirm_overwrite`로
+/// 확인한 뒤 적용. 적용된 계획을 반환하며 취소되면 None
+pub fn init_project(
+    template: &TemplateSpec,
+    locale: Option<&str>,
+    variables: &BTreeMap<String, String>,
+    config: &Config,
+    confirm_overwrite: impl FnOnce(&[&FileOperation]) -> Result<bool>,
+) -> Result<Option<InitPlan>> {
+    let store = config.store();
+    init_with_store(store.as_ref(), template, locale, variables, config, confirm_overwrite)
+}
+
+/// 지정한 템플릿 저장소로 프로젝트 초기화 (토큰 한도는 `config`의 설정 사용)
+pub fn init_with_store(
+    store: &dyn TemplateStore,
+    template: &TemplateSpec,
+    locale: Option<&str>,
+    variables: &BTreeMap<String, String>,
+    config: &Config,
+    confirm_overwrite: impl FnOnce(&[&FileOperation]) -> Result<bool>,
+) -> Result<Option<InitPlan>> {
+    let plan = plan_with_store(store, Path::new("."), template, locale, variables, config)?;
+
+    let conflicts = plan.conflicts();
+    if !conflicts.is_empty() && !confirm_overwrite(&conflicts)? {
+        return Ok(None);
+    }
+
+    apply_plan(&plan)?;
+
+    Ok(Some(plan))
+}
+
+/// 초기화 계획 수립 (템플릿 렌더링과 충돌 확인만 하고 파일은 쓰지 않음)
+///
+/// 로케일을 지정하면 해당 번역본을 사용하고, 번역본이 없으면 기본 파일을 사용하며 경고.
+/// 렌더링 결과가 토큰 한도를 넘으면 설정에 따라 경고를 남기거나 실패.
+/// 생성 파일과 사용한 템플릿 버전, 번역본, 변수를 프로젝트 락파일에 기록하는 작업도 포함
+pub fn plan_init(
+    root: &Path,
+    template: &TemplateSpec,
+    locale: Option<&str>,
+    variables: &BTreeMap<String, String>,
+    config: &Config,
+) -> crate::Result<InitPlan> {
+    plan_with_store(config.store().as_ref(), root, template, locale, variables, config)
+}
+
+/// 지정한 템플릿 저장소로 초기화 계획 수립 (토큰 한도는 `config`의 설정 사용)
+pub fn plan_with_store(
+    store: &dyn TemplateStore,
+    root: &Path,
+    template: &TemplateSpec,
+    locale: Option<&str>,
+    variables: &BTreeMap<String, String>,
+    config: &Config,
+) -> crate::Result<InitPlan> {
+    // 설치된 버전으로 고정해서 렌더링
+    let resolved = store.resolve(template)?.spec();
+    let used = store.resolve_localized(&resolved, locale)?;
+    let renderer = Renderer::new(store).locale(locale);
+    let content = renderer.render(&resolved, variables)?;
+    let source = store.read(&used)?;
+
+    let (metadata, _) = render::split_front_matter(&source)?;
+    let mut warnings = Vec::new();
+
+    // 번역본이 없으면 기본 파일이 요청한 언어로 작성된 경우가 아닐 때만 경고
+    if let Some(locale) = locale {
+        let written_in = metadata.locale.as_deref();
+        if used.locale.is_none()
+            && written_in.is_none_or(|base| template::locale_score(base, locale) == 0)
+        {
+            warnings.push(t!("init.locale_fallback", template, locale));
+        }
+    }
+
+    // 토큰 한도 확인 (extends 대상의 한도 상속)
+    let max_tokens = renderer.max_tokens(&metadata)?;
+    if let Some(limit) = tokens::effective_limit(max_tokens, config.max_tokens) {
+        let estimate = TokenEstimate::of(&content);
+        if estimate.max() > limit {
+            let message = t!("init.token_limit", template, estimate.max(), limit);
+            if config.fail_on_token_limit {
+                return Err(Error::Validation(message));
+            }
+            warnings.push(message);
+        }
+    }
+
+    let path = PathBuf::from(TEAM_RULES_FILE);
+    let overwrite = root.join(&path).exists();
+
+    // 락파일 갱신 (읽을 수 없으면 새로 작성)
+    let mut lock = match Lockfile::load(root) {
+        Ok(lock) => lock.unwrap_or_default(),
+        Err(e) => {
+            warnings.push(t!("init.lockfile_reset", format!("{:#}", e)));
+            Lockfile::default()
+        }
+    };
+    lock.upsert(LockedFile {
+        path: path.clone(),
+        template: resolved.to_string(),
+        generated_at: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        content_hash: lockfile::hash(&content),
+        template_hash: lockfile::hash(&source),
+        locale: used.locale.clone(),
+        variables: variables.clone(),
+        restored_hash: None,
+    });
+
+    Ok(InitPlan {
+        root: root.to_path_buf(),
+        operations: vec![
+            FileOperation {
+                path,
+                content,
+                overwrite,
+                kind: FileKind::Output,
+            },
+            FileOperation {
+                path: PathBuf::from(lockfile::LOCKFILE_NAME),
+                content: lock.to_toml()?,
+                overwrite: root.join(lockfile::LOCKFILE_NAME).exists(),
+                kind: FileKind::Lockfile,
+            },
+        ],
+        warnings,
+        template: resolved.to_string(),
+        locale: used.locale,
+    })
+}
+
+/// 락파일에 고정된 템플릿과 변수로 다시 생성한 결과와 현재 파일 비교 (파일은 쓰지 않음)
+///
+/// 락파일이 없으면 설정의 기본 템플릿으로 생성한 결과와 비교
+pub fn check_project(root: &Path, config: &Config) -> Result<Vec<Drift>> {
+    let targets: Vec<(TemplateSpec, Option<String>, BTreeMap<String, String>)> =
+        match Lockfile::load(root)? {
+        Some(lock) => lock
+            .files
+            .iter()
+            .map(|entry| {
+                let spec = TemplateSpec::parse(&entry.template)
+                    .with_context(|| t!("init.lockfile_template_invalid", entry.template))?;
+                Ok((spec, entry.locale.clone(), entry.variables.clone()))
+            })
+            .collect::<Result<_>>()?,
+        None => vec![(
+            TemplateSpec::parse(&config.default_template)?,
+            config.locale.clone(),
+            BTreeMap::new(),
+        )],
+    };
+
+    let mut drifts = Vec::new();
+    for (spec, locale, variables) in targets {
+        let plan = plan_init(root, &spec, locale.as_deref(), &variables, config)?;
+
+        for op in plan.outputs() {
+            let actual = fs::read_to_string(root.join(&op.path)).ok();
+            if actual.as_deref() != Some(op.content.as_str()) {
+                drifts.push(Drift {
+                    path: op.path.clone(),
+                    expected: op.content.clone(),
+                    actual,
+                });
+            }
+        }
+    }
+
+    Ok(drifts)
+}
+
+/// 초기화 계획 적용
+///
+/// 하나라도 실패하면 이미 작성한 파일과 디렉토리를 모두 되돌리고, 무엇을 되돌렸는지
+/// 에러 메시지에 포함. 성공하면 작성된 파일 경로 목록을 반환
+pub fn apply_plan(plan: &InitPlan) -> Result<Vec<PathBuf>> {
+    // 계획 이후에 새로 생긴 파일이 있으면 디스크를 건드리기 전에 중단
+    for op in &plan.operations {
+        let target = plan.root.join(&op.path);
+        if !op.overwrite && target.exists() {
+            return Err(Error::Conflict(t!("init.created_meanwhile", op.path.display())));
+        }
+    }
+
+    let mut applied = Vec::new();
+    for op in &plan.operations {
+        if let Err(e) = apply_operation(&plan.root, op, &mut applied) {
+            let rollback_errors = rollback(&applied);
+            let reverted: Vec<String> = applied
+                .iter()
+                .filter_map(|step| match step {
+                    AppliedStep::CreatedFile(path) | AppliedStep::ReplacedFile { path, .. } => {
+                        Some(path.display().to_string())
+                    }
+                    AppliedStep::CreatedDir(_) => None,
+                })
+                .collect();
+
+            let mut message = t!("init.write_failed", op.path.display());
+            if reverted.is_empty() {
+                message.push_str(t!("init.nothing_changed"));
+            } else {
+                message.push_str(&t!("init.reverted", reverted.join(", ")));
+            }
+            if !rollback_errors.is_empty() {
+                message.push_str(&t!("init.revert_failed", rollback_errors.join(", ")));
+            }
+
+            return Err(e.context(message).into());
+        }
+    }
+
+    Ok(plan
+        .operations
+        .iter()
+        .map(|op| op.path.clone())
+        .collect())
 }
 
-/// TEAM_RULES.md 파일 작성
-fn write_team_rules(content: &str) -> Result<()> {
-    let file_path = PathBuf::from("rules/TEAM_RULES.md");
+/// 파일 작업 하나 적용 (상위 디렉토리 생성, 기존 파일 백업 후 원자적으로 작성)
+fn apply_operation(
+    root: &Path,
+    op: &FileOperation,
+    applied: &mut Vec<AppliedStep>,
+) -> anyhow::Result<()> {
+    let target = root.join(&op.path);
+
+    // 상위 디렉토리 생성 (새로 만든 디렉토리만 기록)
+    if let Some(parent) = target.parent() {
+        let mut missing: Vec<PathBuf> = parent
+            .ancestors()
+            .take_while(|dir| !dir.as_os_str().is_empty() && !dir.exists())
+            .map(Path::to_path_buf)
+            .collect();
+        missing.reverse();
+
+        for dir in missing {
+            fs::create_dir(&dir)
+                .with_context(|| t!("init.create_dir_failed", dir.display()))?;
+            applied.push(AppliedStep::CreatedDir(dir));
+        }
+    }
 
-    fs::write(&file_path, content)
-        .context("TEAM_RULES.md 파일을 작성할 수 없습니다")?;
+    if target.exists() {
+        let original = fs::read(&target)
+            .with_context(|| t!("init.read_existing_failed", target.display()))?;
+        let backup = match op.kind {
+            FileKind::Output => backup::backup_file(&target)
+                .with_context(|| t!("init.backup_failed", target.display()))?,
+            FileKind::Lockfile => None,
+        };
+        if let Err(e) = backup::write_atomic(&target, &op.content) {
+            // 파일이 바뀌지 않았으므로 방금 만든 백업도 필요 없음
+            if let Some(backup) = &backup {
+                let _ = backup::remove_backup(backup);
+            }
+            return Err(e.into());
+        }
+        applied.push(AppliedStep::ReplacedFile {
+            path: target,
+            original,
+            backup,
+        });
+    } else {
+        backup::write_atomic(&target, &op.content)?;
+        applied.push(AppliedStep::CreatedFile(target));
+    }
 
     Ok(())
 }
 
-/// TEAM_RULES.md 파일 존재 여부 확인
-pub fn team_rules_exists() -> bool {
-    PathBuf::from("rules/TEAM_RULES.md").exists()
+/// 완료된 작업을 역순으로 되돌림 (실패한 항목 목록 반환)
+fn rollback(applied: &[AppliedStep]) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    for step in applied.iter().rev() {
+        let (path, result) = match step {
+            AppliedStep::CreatedFile(path) => (path, fs::remove_file(path).map_err(anyhow::Error::from)),
+            AppliedStep::ReplacedFile {
+                path,
+                original,
+                backup,
+            } => {
+                // 복원에 실패하면 원래 내용이 남은 백업을 지우지 않음
+                let result = backup::write_atomic(path, original).and_then(|()| match backup {
+                    Some(backup) => backup::remove_backup(backup),
+                    None => Ok(()),
+                });
+                (path, result.map_err(anyhow::Error::from))
+            }
+            AppliedStep::CreatedDir(path) => {
+                // 다른 내용이 남아 있으면 유지
+                let _ = fs::remove_dir(path);
+                continue;
+            }
+        };
+
+        if let Err(e) = result {
+            errors.push(format!("{} ({:#})", path.display(), e));
+        }
+    }
+
+    errors
+}
+
+/// TEAM_RULES.md 파일 경로
+pub fn team_rules_path() -> PathBuf {
+    PathBuf::from(TEAM_RULES_FILE)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::store::MemoryStore;
     use crate::template::install_default_template;
-    use std::env;
     use tempfile::tempdir;
 
+    fn plan_with(root: &Path, operations: Vec<FileOperation>) -> InitPlan {
+        InitPlan {
+            root: root.to_path_buf(),
+            operations,
+            warnings: Vec::new(),
+            template: "Rules".to_string(),
+            locale: None,
+        }
+    }
+
+    fn operation(path: &str, content: &str, overwrite: bool) -> FileOperation {
+        FileOperation {
+            path: PathBuf::from(path),
+            content: content.to_string(),
+            overwrite,
+            kind: FileKind::Output,
+        }
+    }
+
     #[test]
     fn test_create_rules_directory() {
         let temp_dir = tempdir().unwrap();
-        let _original_dir = env::current_dir().unwrap();
+        let plan = plan_with(temp_dir.path(), vec![operation(TEAM_RULES_FILE, "", false)]);
 
-        env::set_current_dir(&temp_dir).unwrap();
-        create_rules_directory().unwrap();
+        apply_plan(&plan).unwrap();
 
-        assert!(PathBuf::from("rules").exists());
-
-        // 원래 디렉토리로 복원
-        env::set_current_dir(&_original_dir).ok();
-        // temp_dir는 함수가 끝날 때까지 유지됨
+        assert!(temp_dir.path().join("rules").is_dir());
     }
 
     #[test]
     fn test_write_team_rules() {
         let temp_dir = tempdir().unwrap();
-        let _original_dir = env::current_dir().unwrap();
-
-        env::set_current_dir(&temp_dir).unwrap();
-        create_rules_directory().unwrap();
-        write_team_rules("Test content").unwrap();
+        let rules = temp_dir.path().join(TEAM_RULES_FILE);
 
-        let content = fs::read_to_string("rules/TEAM_RULES.md").unwrap();
-        assert_eq!(content, "Test content");
+        apply_plan(&plan_with(temp_dir.path(), vec![operation(TEAM_RULES_FILE, "Test content", false)]))
+            .unwrap();
+        assert_eq!(fs::read_to_string(&rules).unwrap(), "Test content");
 
-        // 원래 디렉토리로 복원
-        env::set_current_dir(&_original_dir).ok();
-        // temp_dir는 함수가 끝날 때까지 유지됨
+        // 덮어쓰면 이전 파일은 백업됨
+        apply_plan(&plan_with(temp_dir.path(), vec![operation(TEAM_RULES_FILE, "New content", true)]))
+            .unwrap();
+        assert_eq!(fs::read_to_string(&rules).unwrap(), "New content");
+        assert_eq!(backup::list_backups(&rules).unwrap().len(), 1);
     }
 
     #[test]
     fn test_init_project() {
         let temp_dir = tempdir().unwrap();
         let template_dir = tempdir().unwrap();
-        let _original_dir = env::current_dir().unwrap();
 
         let config = Config::new(template_dir.path().to_path_buf());
         install_default_template(&config).unwrap();
 
-        // 작업 디렉토리를 변경한 후 테스트 수행
-        env::set_current_dir(&temp_dir).unwrap();
+        let template = TemplateSpec::parse("Programming-Team").unwrap();
+        let plan = plan_init(temp_dir.path(), &template, None, &BTreeMap::new(), &config).unwrap();
+        assert!(plan.conflicts().is_empty());
+        // 요청한 지정자가 아니라 락파일에 고정한 템플릿
+        assert_eq!(plan.template, "Programming-Team@");
 
-        init_project("Programming-Team", &config).unwrap();
+        let written = apply_plan(&plan).unwrap();
+        assert_eq!(
+            written,
+            vec![PathBuf::from(TEAM_RULES_FILE), PathBuf::from(lockfile::LOCKFILE_NAME)]
+        );
 
-        assert!(team_rules_exists());
-
-        let content = fs::read_to_string("rules/TEAM_RULES.md").unwrap();
+        let content = fs::read_to_string(temp_dir.path().join(TEAM_RULES_FILE)).unwrap();
         assert!(content.contains("AI Software Engineering Team System"));
 
-        // 원래 디렉토리로 복원
-        env::set_current_dir(&_original_dir).ok();
-        // temp_dir와 template_dir는 함수가 끝날 때까지 유지됨
+        // 락파일에 생성 정보 기록
+        let lock = Lockfile::load(temp_dir.path()).unwrap().unwrap();
+        let entry = &lock.files[0];
+        assert_eq!(entry.path, PathBuf::from(TEAM_RULES_FILE));
+        assert_eq!(entry.template, "Programming-Team@");
+        assert_eq!(entry.content_hash, lockfile::hash(&content));
+
+        // 다시 계획하면 기존 규칙 파일만 충돌로 표시됨
+        let plan = plan_init(temp_dir.path(), &template, None, &BTreeMap::new(), &config).unwrap();
+        assert_eq!(plan.conflicts().len(), 1);
+    }
+
+    #[test]
+    fn test_plan_with_memory_store() {
+        let root = tempdir().unwrap();
+        let store = MemoryStore::with_files([
+            ("Rules.md", "# {{ team }} 규칙\n{{> Footer }}"),
+            ("Footer.md", "끝"),
+        ]);
+        let config = Config::new(root.path().join("unused"));
+        let variables = BTreeMap::from([("team".to_string(), "Core".to_string())]);
+
+        let spec = TemplateSpec::parse("Rules").unwrap();
+        let plan = plan_with_store(&store, root.path(), &spec, None, &variables, &config).unwrap();
+        let output = plan.outputs().next().unwrap();
+        assert_eq!(output.content, "# Core 규칙\n끝");
+        assert!(!config.template_path.exists());
+    }
+
+    #[test]
+    fn test_plan_builtin_with_config() {
+        let root = tempdir().unwrap();
+        let template_dir = tempdir().unwrap();
+        fs::write(template_dir.path().join("Rules.md"), "# Rules\n").unwrap();
+        let config = Config::new(template_dir.path().to_path_buf());
+
+        // 설정이 있어도 설치하지 않은 내장 템플릿으로 초기화 가능
+        let spec = TemplateSpec::parse("Frontend").unwrap();
+        let plan = plan_init(root.path(), &spec, None, &BTreeMap::new(), &config).unwrap();
+        assert!(plan.outputs().next().is_some());
+        assert!(!template_dir.path().join("Frontend.md").exists());
+    }
+
+    #[test]
+    fn test_init_localized() {
+        let root = tempdir().unwrap();
+        let template_dir = tempdir().unwrap();
+        let config = Config::new(template_dir.path().to_path_buf());
+        fs::write(template_dir.path().join("Rules.md"), "# 규칙 {{> Intro }}\n").unwrap();
+        fs::write(template_dir.path().join("Rules.en.md"), "# Rules {{> Intro }}\n").unwrap();
+        fs::write(template_dir.path().join("Intro.md"), "소개").unwrap();
+        fs::write(template_dir.path().join("Intro.en.md"), "intro").unwrap();
+
+        // include 대상도 같은 로케일의 번역본 사용
+        let spec = TemplateSpec::parse("Rules").unwrap();
+        let plan = plan_init(root.path(), &spec, Some("en-GB"), &BTreeMap::new(), &config).unwrap();
+        assert_eq!(plan.operations[0].content, "# Rules intro\n");
+        assert_eq!(plan.locale.as_deref(), Some("en"));
+        assert!(plan.warnings.is_empty());
+
+        // 락파일에 사용한 번역본을 기록하고 check도 같은 번역본으로 비교
+        apply_plan(&plan).unwrap();
+        let lock = Lockfile::load(root.path()).unwrap().unwrap();
+        assert_eq!(lock.files[0].locale.as_deref(), Some("en"));
+        assert!(check_project(root.path(), &config).unwrap().is_empty());
+
+        // 번역본이 없으면 기본 파일을 사용하고 경고
+        let plan = plan_init(root.path(), &spec, Some("ja"), &BTreeMap::new(), &config).unwrap();
+        assert_eq!(plan.operations[0].content, "# 규칙 소개\n");
+        assert_eq!(plan.locale, None);
+        assert_eq!(plan.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_check_project() {
+        let root = tempdir().unwrap();
+        let template_dir = tempdir().unwrap();
+        let config = Config::new(template_dir.path().to_path_buf());
+        fs::write(template_dir.path().join("Rules@1.0.md"), "# {{ team }}\n").unwrap();
+
+        let spec = TemplateSpec::parse("Rules").unwrap();
+        let variables = BTreeMap::from([("team".to_string(), "Core".to_string())]);
+        apply_plan(&plan_init(root.path(), &spec, None, &variables, &config).unwrap()).unwrap();
+        assert!(check_project(root.path(), &config).unwrap().is_empty());
+
+        // 새 버전이 설치되어도 락파일에 고정된 버전과 변수로 비교
+        fs::write(template_dir.path().join("Rules@2.0.md"), "# New\n").unwrap();
+        assert!(check_project(root.path(), &config).unwrap().is_empty());
+
+        // 파일을 직접 수정하면 차이 발생
+        fs::write(root.path().join(TEAM_RULES_FILE), "# Edited\n").unwrap();
+        let drifts = check_project(root.path(), &config).unwrap();
+        assert_eq!(drifts.len(), 1);
+        assert_eq!(drifts[0].expected, "# Core\n");
+        assert_eq!(drifts[0].actual.as_deref(), Some("# Edited\n"));
+
+        // 파일이 없어도 차이로 보고하며, 아무 파일도 쓰지 않음
+        fs::remove_file(root.path().join(TEAM_RULES_FILE)).unwrap();
+        let drifts = check_project(root.path(), &config).unwrap();
+        assert_eq!(drifts[0].actual, None);
+        assert!(!root.path().join(TEAM_RULES_FILE).exists());
+    }
+
+    #[test]
+    fn test_check_pins_unversioned_template() {
+        let root = tempdir().unwrap();
+        let template_dir = tempdir().unwrap();
+        let source_dir = tempdir().unwrap();
+        let config = Config::new(template_dir.path().to_path_buf());
+        let source = source_dir.path().join("team.md");
+
+        // install Team, init Team, install Team@2.0
+        fs::write(&source, "# Team v1\n").unwrap();
+        template::install_template(&source, &TemplateSpec::parse("Team").unwrap(), &config)
+            .unwrap();
+        let spec = TemplateSpec::parse("Team").unwrap();
+        apply_plan(&plan_init(root.path(), &spec, None, &BTreeMap::new(), &config).unwrap())
+            .unwrap();
+        fs::write(&source, "# Team v2\n").unwrap();
+        template::install_template(&source, &TemplateSpec::parse("Team@2.0").unwrap(), &config)
+            .unwrap();
+
+        // 락파일은 사용한 버전 없는 파일을 가리키므로 차이 없음
+        let lock = Lockfile::load(root.path()).unwrap().unwrap();
+        assert_eq!(lock.files[0].template, "Team@");
+        assert!(check_project(root.path(), &config).unwrap().is_empty());
+
+        let status = lockfile::inspect(root.path(), &lock.files[0], &config);
+        assert_eq!(status.template, lockfile::TemplateState::Unchanged);
+    }
+
+    #[test]
+    fn test_plan_init_token_limit() {
+        let temp_dir = tempdir().unwrap();
+        let template_dir = tempdir().unwrap();
+        let mut config = Config::new(template_dir.path().to_path_buf());
+
+        fs::write(
+            template_dir.path().join("Small.md"),
+            "+++\nmax_tokens = 100\n+++\nshort rules",
+        )
+        .unwrap();
+        fs::write(template_dir.path().join("Large.md"), "word ".repeat(200)).unwrap();
+
+        let small = TemplateSpec::parse("Small").unwrap();
+        let large = TemplateSpec::parse("Large").unwrap();
+        let variables = BTreeMap::new();
+
+        // 한도 안이면 경고 없음
+        let plan = plan_init(temp_dir.path(), &small, None, &variables, &config).unwrap();
+        assert!(plan.warnings.is_empty());
+
+        // extends 대상의 한도 상속
+        fs::write(
+            template_dir.path().join("Extended.md"),
+            format!("+++\nextends = \"Small\"\n+++\n{}", "word ".repeat(200)),
+        )
+        .unwrap();
+        let extended = TemplateSpec::parse("Extended").unwrap();
+        let plan = plan_init(temp_dir.path(), &extended, None, &variables, &config).unwrap();
+        assert_eq!(plan.warnings.len(), 1);
+
+        // 설정의 한도를 넘으면 경고
+        config.max_tokens = Some(100);
+        let plan = plan_init(temp_dir.path(), &large, None, &variables, &config).unwrap();
+        assert_eq!(plan.warnings.len(), 1);
+
+        // 실패로 설정하면 계획 단계에서 중단
+        config.fail_on_token_limit = true;
+        assert!(plan_init(temp_dir.path(), &large, None, &variables, &config).is_err());
+        assert!(!temp_dir.path().join("rules").exists());
+    }
+
+    #[test]
+    fn test_init_over_token_limit() {
+        let temp_dir = tempdir().unwrap();
+        let template_dir = tempdir().unwrap();
+        let mut config = Config::new(template_dir.path().to_path_buf());
+        config.max_tokens = Some(100);
+        config.fail_on_token_limit = true;
+        fs::write(template_dir.path().join("Large.md"), "word ".repeat(200)).unwrap();
+        let large = TemplateSpec::parse("Large").unwrap();
+        let variables = BTreeMap::new();
+
+        // 검증 실패(종료 코드 5)로 중단하고 파일은 쓰지 않음
+        let err = plan_init(temp_dir.path(), &large, None, &variables, &config).unwrap_err();
+        assert!(matches!(err, Error::Validation(_)));
+        assert_eq!(crate::error::exit_code(&err.into()), crate::error::EXIT_VALIDATION);
+        assert!(!temp_dir.path().join(TEAM_RULES_FILE).exists());
+
+        // 실패 설정을 끄면 경고만 남기고 작성
+        config.fail_on_token_limit = false;
+        let plan = plan_init(temp_dir.path(), &large, None, &variables, &config).unwrap();
+        assert_eq!(plan.warnings.len(), 1);
+        apply_plan(&plan).unwrap();
+        assert!(temp_dir.path().join(TEAM_RULES_FILE).exists());
+    }
+
+    #[test]
+    fn test_apply_plan_rolls_back_on_failure() {
+        let temp_dir = tempdir().unwrap();
+        let existing = temp_dir.path().join("rules/EXISTING.md");
+        fs::create_dir_all(existing.parent().unwrap()).unwrap();
+        fs::write(&existing, "original").unwrap();
+        // 파일 대신 디렉토리가 있어 작성에 실패하는 경로
+        fs::create_dir_all(temp_dir.path().join("rules/BROKEN.md")).unwrap();
+
+        let plan = plan_with(
+            temp_dir.path(),
+            vec![
+                operation("docs/NEW.md", "new", false),
+                operation("rules/EXISTING.md", "changed", true),
+                operation("rules/BROKEN.md", "broken", true),
+            ],
+        );
+
+        let err = apply_plan(&plan).unwrap_err();
+        let message = format!("{:#}", err);
+        assert!(message.contains("BROKEN.md"));
+        assert!(message.contains("NEW.md"));
+
+        // 새로 만든 파일과 디렉토리는 삭제되고 기존 파일은 원래 내용으로 복원
+        assert!(!temp_dir.path().join("docs").exists());
+        assert_eq!(fs::read_to_string(&existing).unwrap(), "original");
+        // 덮어쓰기 전에 만든 백업도 남지 않음
+        assert!(!temp_dir.path().join("rules").join(backup::BACKUP_DIR_NAME).exists());
+
+        // 원래 있던 백업은 유지
+        backup::backup_file(&existing).unwrap();
+        assert!(apply_plan(&plan).is_err());
+        assert_eq!(backup::list_backups(&existing).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_apply_plan_checks_conflicts_before_writing() {
+        let temp_dir = tempdir().unwrap();
+        fs::create_dir_all(temp_dir.path().join("rules")).unwrap();
+        fs::write(temp_dir.path().join("rules/LATE.md"), "created after planning").unwrap();
+
+        let plan = plan_with(
+            temp_dir.path(),
+            vec![
+                operation("rules/FIRST.md", "first", false),
+                operation("rules/LATE.md", "late", false),
+            ],
+        );
+
+        assert!(apply_plan(&plan).is_err());
+        assert!(!temp_dir.path().join("rules/FIRST.md").exists());
     }
 }
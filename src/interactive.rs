@@ -1,11 +1,20 @@
-use anyhow::{Context, Result};
+use anyhow::Result;
 use colored::Colorize;
 use dialoguer::{Confirm, Input, Select};
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
-use crate::config::{self, Config};
-use crate::init;
-use crate::template;
+use init_ai::config::{self, Config};
+use init_ai::i18n::t;
+use init_ai::init;
+use init_ai::name;
+use init_ai::render;
+use init_ai::template;
+use init_ai::tokens::TokenEstimate;
+use init_ai::{LayeredStore, MemoryStore, TemplateSpec, TemplateStore};
+
+use crate::output::{Output, Usage};
+use crate::picker::{self, Item};
 
 /// 메인 메뉴 옵션
 enum MainMenuOption {
@@ -15,34 +24,38 @@ enum MainMenuOption {
 }
 
 /// 대화형 모드 실행
-pub fn run_interactive_mode() -> Result<()> {
-    println!("{}", "=== initai 대화형 모드 ===".cyan().bold());
-    println!();
+///
+/// `variables`와 `locale`은 프로젝트 시작하기에서 사용 (`init --var`, `--locale`로 지정한 값)
+pub fn run_interactive_mode(
+    variables: &BTreeMap<String, String>,
+    locale: Option<&str>,
+    out: Output,
+) -> Result<()> {
+    if out.is_json() {
+        return Err(Usage(t!("interactive.json_unsupported").to_string()).into());
+    }
+    if !out.can_prompt() {
+        anyhow::bail!(t!("interactive.not_terminal"));
+    }
 
-    // 설정 파일 확인
-    let config = if config::config_exists()? {
-        config::load_config()?
-    } else {
-        // 설정 파일이 없으면 템플릿 경로 설정
-        println!("{}", "⚠️  템플릿 저장 경로가 설정되지 않았습니다.".yellow());
-        setup_template_path()?
-    };
+    println!("{}", t!("interactive.title").cyan().bold());
+    println!();
 
     loop {
         // 메인 메뉴 표시
         match show_main_menu()? {
             MainMenuOption::StartProject => {
-                if let Err(e) = start_project(&config) {
+                if let Err(e) = start_project(variables, locale, out) {
                     eprintln!("{} {}", "✗".red(), e.to_string().red());
                 }
             }
             MainMenuOption::ManageTemplates => {
-                if let Err(e) = manage_templates(&config) {
+                if let Err(e) = manage_templates(out) {
                     eprintln!("{} {}", "✗".red(), e.to_string().red());
                 }
             }
             MainMenuOption::Exit => {
-                println!("{}", "종료합니다.".yellow());
+                println!("{}", t!("interactive.exit").yellow());
                 break;
             }
         }
@@ -55,13 +68,13 @@ pub fn run_interactive_mode() -> Result<()> {
 /// 메인 메뉴 표시
 fn show_main_menu() -> Result<MainMenuOption> {
     let options = vec![
-        "프로젝트 시작하기",
-        "템플릿 관리",
-        "종료",
+        t!("interactive.menu_start"),
+        t!("interactive.menu_manage"),
+        t!("interactive.menu_exit"),
     ];
 
     let selection = Select::new()
-        .with_prompt("원하는 작업을 선택하세요")
+        .with_prompt(t!("interactive.menu_prompt"))
         .items(&options)
         .default(0)
         .interact()?;
@@ -75,51 +88,107 @@ fn show_main_menu() -> Result<MainMenuOption> {
 }
 
 /// 프로젝트 시작하기
-fn start_project(config: &Config) -> Result<()> {
-    // 템플릿 선택
-    let Some(template_name) = prompt_template_selection(config)? else {
+///
+/// 설정 파일이 없으면 `init` 명령처럼 내장 템플릿만 사용. 로케일을 지정하지 않으면
+/// 설정 파일의 locale 사용
+fn start_project(
+    variables: &BTreeMap<String, String>,
+    locale: Option<&str>,
+    out: Output,
+) -> Result<()> {
+    let config = config::load_or_builtin()?;
+
+    // 템플릿 선택 (여러 개 선택하면 조합한 템플릿 생성)
+    let Some(selection) = prompt_template_selection(&config)? else {
         return Ok(());
     };
+    let (template_name, composed) = match selection.as_slice() {
+        [single] => (single.clone(), None),
+        parts => {
+            let (spec, content) = compose_templates_interactive(parts, &config)?;
+            (spec, Some(content))
+        }
+    };
+
+    // 조합한 템플릿은 렌더링과 덮어쓰기 확인이 끝날 때까지 메모리에 둔 채로 계획 수립
+    let store = LayeredStore::new().with(MemoryStore::new()).with(config.store());
+    if let Some(content) = &composed {
+        store.create(&template_name, None, content)?;
+    }
+
+    let locale = locale.or(config.locale.as_deref());
+    let plan = init::plan_with_store(
+        &store,
+        Path::new("."),
+        &template_name,
+        locale,
+        variables,
+        &config,
+    )?;
 
     // 파일 존재 시 덮어쓰기 확인
-    if init::team_rules_exists() {
-        let overwrite = confirm_overwrite()?;
-        if !overwrite {
-            println!("{}", "작업이 취소되었습니다.".yellow());
-            return Ok(());
-        }
+    let conflicts = plan.conflicts();
+    if !conflicts.is_empty() && !confirm_overwrite(&conflicts, out)? {
+        println!("{}", t!("common.cancelled").yellow());
+        return Ok(());
     }
 
-    // 프로젝트 초기화
-    init::init_project(&template_name, config)?;
+    // 락파일이 가리키는 템플릿이 항상 남아 있도록 조합한 템플릿을 먼저 저장한 뒤 파일 작성
+    // (저장에 실패하면 프로젝트 파일은 건드리지 않음)
+    if let Some(content) = &composed {
+        crate::with_writable_config(out, |config| {
+            config.store().create(&template_name, None, content)
+        })?;
+        let names: Vec<String> = selection.iter().map(|p| p.to_string()).collect();
+        println!(
+            "{} {}",
+            "✓".green(),
+            t!("interactive.composed", template_name.to_string().cyan(), names.join(", "))
+        );
+    }
 
-    println!(
-        "{} rules/TEAM_RULES.md 파일이 생성되었습니다. (템플릿: {})",
-        "✓".green(),
-        template_name.cyan()
-    );
+    init::apply_plan(&plan)?;
+
+    for op in plan.outputs() {
+        println!(
+            "{} {}",
+            "✓".green(),
+            t!("common.file_created", op.path.display(), template_name.to_string().cyan())
+        );
+        println!(
+            "  {} {}",
+            t!("common.estimated_tokens").cyan(),
+            TokenEstimate::of(&op.content)
+        );
+    }
+    for warning in &plan.warnings {
+        out.warn(warning.yellow());
+    }
 
     Ok(())
 }
 
 /// 템플릿 관리 메뉴
-fn manage_templates(config: &Config) -> Result<()> {
+fn manage_templates(out: Output) -> Result<()> {
     loop {
+        // 설치하면 설정 파일이 새로 생길 수 있으므로 매번 로드
+        let config = config::load_or_builtin()?;
+        let config = &config;
         let options = vec![
-            "템플릿 등록",
-            "템플릿 삭제",
-            "템플릿 목록 보기",
-            "돌아가기",
+            t!("interactive.manage_install"),
+            t!("interactive.manage_remove"),
+            t!("interactive.manage_list"),
+            t!("common.back"),
         ];
 
         let selection = Select::new()
-            .with_prompt("템플릿 관리")
+            .with_prompt(t!("interactive.menu_manage"))
             .items(&options)
             .default(0)
             .interact()?;
 
         match selection {
-            0 => install_template_interactive(config)?,
+            0 => install_template_interactive(out)?,
             1 => remove_template_interactive(config)?,
             2 => list_templates_interactive(config)?,
             3 => break,
@@ -132,15 +201,15 @@ fn manage_templates(config: &Config) -> Result<()> {
 }
 
 /// 템플릿 등록 (대화형)
-fn install_template_interactive(config: &Config) -> Result<()> {
+fn install_template_interactive(out: Output) -> Result<()> {
     let file_path: String = Input::new()
-        .with_prompt("템플릿 파일 경로를 입력하세요 (취소: 빈 입력 후 Enter)")
+        .with_prompt(t!("interactive.file_path_prompt"))
         .allow_empty(true)
         .interact_text()?;
 
     // 빈 입력 시 취소
     if file_path.trim().is_empty() {
-        println!("{}", "작업이 취소되었습니다.".yellow());
+        println!("{}", t!("common.cancelled").yellow());
         return Ok(());
     }
 
@@ -148,11 +217,11 @@ fn install_template_interactive(config: &Config) -> Result<()> {
 
     // 파일 존재 확인
     if !path.exists() {
-        anyhow::bail!("파일을 찾을 수 없습니다: {}", file_path);
+        anyhow::bail!(t!("common.file_not_found", file_path));
     }
 
     let template_name: String = Input::new()
-        .with_prompt("템플릿 이름을 입력하세요 (취소: 빈 입력, 기본값: 파일명)")
+        .with_prompt(t!("interactive.name_prompt"))
         .allow_empty(true)
         .interact_text()?;
 
@@ -160,19 +229,25 @@ fn install_template_interactive(config: &Config) -> Result<()> {
         // 빈 입력인 경우 파일명 사용 (취소가 아님)
         path.file_stem()
             .and_then(|s| s.to_str())
-            .ok_or_else(|| anyhow::anyhow!("파일명을 확인할 수 없습니다"))?
+            .ok_or_else(|| anyhow::anyhow!(t!("common.file_stem_unknown")))?
             .to_string()
     } else {
         template_name
     };
+    let (spec, locale) = name::parse_localized(&name)?;
 
-    template::install_template(&path, &name, config)?;
+    let warnings = crate::with_writable_config(out, |config| {
+        template::install_localized_template(&path, &spec, locale.as_deref(), config)
+    })?;
 
     println!(
-        "{} 템플릿 '{}'이(가) 설치되었습니다.",
+        "{} {}",
         "✓".green(),
-        name.cyan()
+        t!("common.template_installed", name.to_string().cyan())
     );
+    for issue in warnings {
+        out.warn(issue);
+    }
 
     Ok(())
 }
@@ -182,15 +257,15 @@ fn remove_template_interactive(config: &Config) -> Result<()> {
     let templates = template::list_templates(config)?;
 
     if templates.is_empty() {
-        println!("{}", "사용 가능한 템플릿이 없습니다.".yellow());
+        println!("{}", t!("common.no_templates").yellow());
         return Ok(());
     }
 
-    let mut template_names: Vec<String> = templates.iter().map(|t| t.name.clone()).collect();
-    template_names.push("돌아가기".to_string());
+    let mut template_names: Vec<String> = templates.iter().map(|t| t.name.to_string()).collect();
+    template_names.push(t!("common.back").to_string());
 
     let selection = Select::new()
-        .with_prompt("삭제할 템플릿을 선택하세요")
+        .with_prompt(t!("interactive.remove_prompt"))
         .items(&template_names)
         .default(0)
         .interact()?;
@@ -200,26 +275,24 @@ fn remove_template_interactive(config: &Config) -> Result<()> {
     }
 
     let template_name = &template_names[selection];
+    let spec = TemplateSpec::from(templates[selection].name.clone());
 
     let confirm = Confirm::new()
-        .with_prompt(format!(
-            "템플릿 '{}'을(를) 삭제하시겠습니까?",
-            template_name
-        ))
+        .with_prompt(t!("common.confirm_remove", template_name))
         .default(false)
         .interact()?;
 
     if !confirm {
-        println!("{}", "작업이 취소되었습니다.".yellow());
+        println!("{}", t!("common.cancelled").yellow());
         return Ok(());
     }
 
-    template::remove_template(template_name, config)?;
+    template::remove_template(&spec, config)?;
 
     println!(
-        "{} 템플릿 '{}'이(가) 삭제되었습니다.",
+        "{} {}",
         "✓".green(),
-        template_name.cyan()
+        t!("common.template_removed", template_name.cyan())
     );
 
     Ok(())
@@ -230,80 +303,98 @@ fn list_templates_interactive(config: &Config) -> Result<()> {
     let templates = template::list_templates(config)?;
 
     if templates.is_empty() {
-        println!("{}", "사용 가능한 템플릿이 없습니다.".yellow());
+        println!("{}", t!("common.no_templates").yellow());
     } else {
-        println!("{}", "사용 가능한 템플릿:".cyan());
+        println!("{}", t!("common.available_templates").cyan());
         for (i, template) in templates.iter().enumerate() {
-            println!("  {}. {}", i + 1, template.name.cyan());
+            println!("  {}. {}", i + 1, template.id().cyan());
         }
     }
 
     Ok(())
 }
 
-/// 템플릿 경로 설정
-fn setup_template_path() -> Result<Config> {
-    let default_path = dirs::config_dir()
-        .context("설정 디렉토리를 찾을 수 없습니다")?
-        .join("initai")
-        .join("templates");
-
-    let path_str: String = Input::new()
-        .with_prompt("템플릿을 저장할 경로를 입력하세요")
-        .default(default_path.to_string_lossy().to_string())
-        .interact_text()?;
-
-    let path = PathBuf::from(path_str);
-
-    config::set_template_path(path.clone())?;
-    println!("{} 템플릿 저장 경로 설정 완료", "✓".green());
-
-    let config = config::load_config()?;
-
-    // 기본 템플릿 설치
-    template::install_default_template(&config)?;
-    println!(
-        "{} 기본 템플릿 '{}' 설치 완료",
-        "✓".green(),
-        "Programming-Team".cyan()
-    );
-    println!();
-
-    Ok(config)
-}
-
-/// 템플릿 선택 프롬프트
-fn prompt_template_selection(config: &Config) -> Result<Option<String>> {
+/// 템플릿 선택 프롬프트 (이름, 설명, 태그 퍼지 검색, Tab으로 여러 개 선택)
+fn prompt_template_selection(config: &Config) -> Result<Option<Vec<TemplateSpec>>> {
     let templates = template::list_templates(config)?;
 
     if templates.is_empty() {
-        anyhow::bail!("사용 가능한 템플릿이 없습니다. 'initai template install' 명령으로 템플릿을 추가하세요.");
+        anyhow::bail!("{} {}", t!("common.no_templates"), t!("common.install_hint"));
     }
 
-    println!("{}", "사용 가능한 템플릿:".cyan());
+    // 읽을 수 없는 템플릿은 경고만 하고 설명과 미리보기 없이 표시
+    let items: Vec<Item> = templates
+        .iter()
+        .map(|t| {
+            let item = template::get_template_content(&t.spec(), config).and_then(|content| {
+                let (metadata, body) = render::split_front_matter(&content)?;
+                Ok(Item {
+                    name: t.name.to_string(),
+                    description: metadata.description.unwrap_or_default(),
+                    tags: metadata.tags,
+                    preview: body
+                        .lines()
+                        .skip_while(|line| line.trim().is_empty())
+                        .take(picker::PREVIEW_LINES)
+                        .map(str::to_string)
+                        .collect(),
+                })
+            });
+            item.unwrap_or_else(|e| {
+                let warning = t!("interactive.item_unreadable", t.name, format!("{:#}", e));
+                eprintln!("{} {}", "⚠️".yellow(), warning);
+                Item {
+                    name: t.name.to_string(),
+                    ..Item::default()
+                }
+            })
+        })
+        .collect();
 
-    let mut template_names: Vec<String> = templates.iter().map(|t| t.name.clone()).collect();
-    template_names.push("돌아가기".to_string());
+    let Some(indices) = picker::pick(t!("interactive.select_prompt"), items)? else {
+        return Ok(None);
+    };
 
-    let selection = Select::new()
-        .with_prompt("템플릿을 선택하세요")
-        .items(&template_names)
-        .default(0)
-        .interact()?;
+    Ok(Some(
+        indices
+            .into_iter()
+            .map(|i| TemplateSpec::from(templates[i].name.clone()))
+            .collect(),
+    ))
+}
 
-    if selection == template_names.len() - 1 {
-        return Ok(None);
-    }
+/// 선택한 여러 템플릿을 조합한 새 템플릿의 이름과 내용 (저장하지 않음)
+fn compose_templates_interactive(
+    parts: &[TemplateSpec],
+    config: &Config,
+) -> Result<(TemplateSpec, String)> {
+    let names: Vec<String> = parts.iter().map(|p| p.to_string()).collect();
+    let name: String = Input::new()
+        .with_prompt(t!("interactive.compose_name_prompt"))
+        .default(names.join("+"))
+        .validate_with(|input: &String| -> Result<(), String> {
+            match TemplateSpec::parse(input.trim()) {
+                Ok(spec) if template::template_exists(&spec.name, config).unwrap_or(false) => {
+                    Err(t!("template.exists", spec.name))
+                }
+                Ok(_) => Ok(()),
+                Err(e) => Err(e.to_string()),
+            }
+        })
+        .interact_text()?;
+
+    let spec = TemplateSpec::parse(name.trim())?;
+    let content = template::composed_content(parts, config)?;
 
-    Ok(Some(template_names[selection].clone()))
+    Ok((spec, content))
 }
 
-/// 덮어쓰기 확인
-fn confirm_overwrite() -> Result<bool> {
-    let confirm = Confirm::new()
-        .with_prompt("rules/TEAM_RULES.md 파일이 이미 존재합니다. 덮어쓰시겠습니까?")
-        .default(false)
-        .interact()?;
+/// 덮어쓰기 확인 (init 명령과 대화형 모드 공용)
+pub fn confirm_overwrite(conflicts: &[&init::FileOperation], out: Output) -> Result<bool> {
+    let paths: Vec<String> = conflicts
+        .iter()
+        .map(|op| op.path.display().to_string())
+        .collect();
 
-    Ok(confirm)
+    out.confirm(&t!("common.confirm_overwrite", paths.join(", ")), false)
 }
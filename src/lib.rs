@@ -0,0 +1,54 @@
+//! initai 라이브러리
+//!
+//! 템플릿 저장소 조회, 렌더링, 프로젝트 초기화 계획을 다른 도구에서도 사용할 수 있도록
+//! 공개. `initai` 바이너리는 이 라이브러리 위의 명령줄 클라이언트
+//!
+//! ```no_run
+//! use std::collections::BTreeMap;
+//! use std::path::Path;
+//!
+//! use init_ai::{Config, FsStore, Renderer, TemplateSpec, TemplateStore};
+//!
+//! # fn main() -> init_ai::Result<()> {
+//! let store = FsStore::new("/path/to/templates");
+//! for template in store.list()? {
+//!     println!("{}", template.id());
+//! }
+//!
+//! // 렌더링만 하거나, 초기화 계획(작성할 파일 목록)을 세울 수 있음
+//! let spec = TemplateSpec::parse("Programming-Team")?;
+//! let rendered = Renderer::new(&store).render(&spec, &BTreeMap::new())?;
+//!
+//! let config = Config::new(store.root().to_path_buf());
+//! let plan = init_ai::init::plan_with_store(&store, Path::new("."), &spec, None, &BTreeMap::new(), &config)?;
+//! for op in plan.operations {
+//!     println!("{} ({} bytes)", op.path.display(), op.content.len());
+//! }
+//! # let _ = rendered;
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod backup;
+pub mod config;
+pub mod doctor;
+pub mod embedded;
+pub mod error;
+pub mod i18n;
+pub mod init;
+pub mod lint;
+pub mod lockfile;
+pub(crate) mod messages;
+pub mod name;
+pub mod render;
+pub mod store;
+pub mod template;
+pub mod tokens;
+
+pub use config::Config;
+pub use error::{Error, Result};
+pub use init::{FileKind, FileOperation, InitPlan};
+pub use name::{TemplateName, TemplateSpec};
+pub use render::Renderer;
+pub use store::{EmbeddedStore, FsStore, LayeredStore, MemoryStore, TemplateStore};
+pub use template::Template;
@@ -0,0 +1,607 @@
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::Path;
+
+use crate::config::Config;
+use crate::error::Result;
+use crate::i18n::t;
+use crate::name::TemplateSpec;
+use crate::render::{self, TemplateMetadata};
+use crate::template;
+
+/// 이 크기를 넘는 템플릿은 경고 (AI 도구의 컨텍스트를 과도하게 차지)
+pub const LARGE_TEMPLATE_SIZE: usize = 64 * 1024;
+
+/// 프론트 매터에서 인식하는 키
+const KNOWN_METADATA_KEYS: &[&str] = &[
+    "description",
+    "version",
+    "tags",
+    "extends",
+    "max_tokens",
+    "variables",
+    "locale",
+];
+
+/// 검사 결과 심각도
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => f.write_str("warning"),
+            Severity::Error => f.write_str("error"),
+        }
+    }
+}
+
+/// 검사에서 발견된 문제
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LintIssue {
+    pub severity: Severity,
+    /// 규칙 식별자 (예: `undefined-variable`)
+    pub rule: &'static str,
+    /// 문제가 발견된 줄 (1부터 시작, 파일 전체 기준)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    /// 문제가 발견된 열 (1부터 시작, 문자 단위)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl LintIssue {
+    fn new(severity: Severity, rule: &'static str, line: Option<usize>, message: String) -> Self {
+        Self {
+            severity,
+            rule,
+            line,
+            column: None,
+            message,
+        }
+    }
+
+    /// 문제가 발견된 위치의 열 지정
+    fn at_column(mut self, column: Option<usize>) -> Self {
+        self.column = column;
+        self
+    }
+}
+
+impl fmt::Display for LintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(
+                f,
+                "{}:{}:{} [{}] {}",
+                line, column, self.severity, self.rule, self.message
+            ),
+            (Some(line), None) => {
+                write!(f, "{}:{} [{}] {}", line, self.severity, self.rule, self.message)
+            }
+            _ => write!(f, "{} [{}] {}", self.severity, self.rule, self.message),
+        }
+    }
+}
+
+/// 템플릿 하나의 검사 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct LintReport {
+    /// 템플릿 이름 또는 파일 경로
+    pub target: String,
+    pub issues: Vec<LintIssue>,
+}
+
+impl LintReport {
+    pub fn has_errors(&self) -> bool {
+        self.issues.iter().any(|i| i.severity == Severity::Error)
+    }
+}
+
+/// 템플릿 내용 검사
+///
+/// `config`가 주어지면 include와 extends 대상이 설치되어 있는지도 확인
+pub fn lint_content(content: &str, config: Option<&Config>) -> Vec<LintIssue> {
+    lint(content, config, Severity::Error)
+}
+
+/// 설치 전 템플릿 내용 검사
+///
+/// 서로 참조하는 템플릿을 하나씩 설치할 수 있도록 아직 설치되지 않은 include, extends
+/// 대상은 경고로만 표시
+pub fn lint_before_install(content: &str, config: &Config) -> Vec<LintIssue> {
+    lint(content, Some(config), Severity::Warning)
+}
+
+/// 템플릿 내용 검사 (`missing`: 설치되지 않은 참조 대상의 심각도)
+///
+/// 한 검사에서 오류가 나와도 나머지 검사를 모두 실행하고, 줄과 열 순서로 정렬해서 반환
+/// (위치가 없는 파일 전체의 문제가 먼저)
+fn lint(content: &str, config: Option<&Config>, missing: Severity) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+
+    if content.len() > LARGE_TEMPLATE_SIZE {
+        issues.push(LintIssue::new(
+            Severity::Warning,
+            "size",
+            None,
+            t!("lint.too_large", content.len() / 1024, LARGE_TEMPLATE_SIZE / 1024),
+        ));
+    }
+
+    let (metadata, body) = match render::split_front_matter(content) {
+        Ok(split) => split,
+        Err(e) => {
+            issues.push(LintIssue::new(
+                Severity::Error,
+                "front-matter",
+                Some(1),
+                format!("{:#}", e),
+            ));
+            // 프론트 매터 내용만 잘못되었으면 본문은 계속 검사 (닫히지 않았으면 본문을 알 수 없음)
+            match render::split_front_matter_raw(content) {
+                Ok((_, body)) => (TemplateMetadata::default(), body),
+                Err(_) => {
+                    issues.sort_by_key(|issue| (issue.line, issue.column));
+                    return issues;
+                }
+            }
+        }
+    };
+    // 본문 시작 줄 (프론트 매터 줄 수만큼 밀림)
+    let body_offset = content[..content.len() - body.len()].lines().count();
+
+    check_metadata_keys(content, body, &mut issues);
+
+    let (tags, unclosed) = scan_tags(body, body_offset);
+    if let Some((line, column)) = unclosed {
+        issues.push(
+            LintIssue::new(
+                Severity::Error,
+                "syntax",
+                Some(line),
+                t!("render.unclosed_tag").to_string(),
+            )
+            .at_column(Some(column)),
+        );
+    }
+    check_conditionals(&tags, &mut issues);
+
+    // 처리하지 않고 그대로 출력되는 태그
+    for tag in tags.iter().filter(|tag| !render::is_known_tag(&tag.text)) {
+        issues.push(
+            LintIssue::new(
+                Severity::Warning,
+                "unknown-tag",
+                Some(tag.line),
+                t!("lint.unknown_tag", format!("{{{{{}}}}}", tag.text)),
+            )
+            .at_column(Some(tag.column)),
+        );
+    }
+
+    // 변수 사용 확인 (조건문 짝이 맞지 않아도 확인할 수 있도록 태그 기준)
+    let mut used = BTreeSet::new();
+    let mut conditions = BTreeSet::new();
+    let mut references: Vec<&Tag> = Vec::new();
+    for tag in &tags {
+        if let Some(name) = tag.include() {
+            if !references.iter().any(|r| r.include() == Some(name)) {
+                references.push(tag);
+            }
+        } else if let Some(condition) = tag.text.strip_prefix("#if ") {
+            conditions.insert(condition.trim());
+        } else if render::is_known_tag(&tag.text) && tag.text != "else" && tag.text != "/if" {
+            used.insert(tag.text.as_str());
+        }
+    }
+
+    for name in &used {
+        if !metadata.variables.contains_key(*name) {
+            let tag = tags.iter().find(|tag| tag.text == *name);
+            issues.push(
+                LintIssue::new(
+                    Severity::Warning,
+                    "undefined-variable",
+                    tag.map(|tag| tag.line),
+                    t!("lint.undefined_variable", name),
+                )
+                .at_column(tag.map(|tag| tag.column)),
+            );
+        }
+    }
+
+    // include, extends 대상도 변수를 사용할 수 있으므로 참조가 없을 때만 확인
+    if references.is_empty() && metadata.extends.is_none() {
+        for name in metadata.variables.keys() {
+            if !used.contains(name.as_str()) && !conditions.contains(name.as_str()) {
+                issues.push(LintIssue::new(
+                    Severity::Warning,
+                    "unused-variable",
+                    None,
+                    t!("lint.unused_variable", name),
+                ));
+            }
+        }
+    }
+
+    // include, extends 대상 확인
+    if let Some(parent) = &metadata.extends {
+        check_reference(parent, None, config, missing, &mut issues);
+    }
+    for tag in references {
+        if let Some(reference) = tag.include() {
+            check_reference(reference, Some(tag), config, missing, &mut issues);
+        }
+    }
+
+    check_headings(body, body_offset, &mut issues);
+
+    issues.sort_by_key(|issue| (issue.line, issue.column));
+    issues
+}
+
+/// 설치된 템플릿 검사
+pub fn lint_template(spec: &TemplateSpec, config: &Config) -> Result<LintReport> {
+    let template = template::resolve_template(spec, config)?;
+    let content = template::get_template_content(&template.spec(), config)?;
+
+    Ok(LintReport {
+        target: template.id(),
+        issues: lint_content(&content, Some(config)),
+    })
+}
+
+/// 설치하지 않은 템플릿 파일 검사 (설치와 같은 크기·파일 형식 제한 적용)
+pub fn lint_file(path: &Path, config: &Config) -> Result<LintReport> {
+    let content = template::read_template_source(path)?;
+
+    Ok(LintReport {
+        target: path.display().to_string(),
+        issues: lint_content(&content, Some(config)),
+    })
+}
+
+/// 알 수 없는 프론트 매터 키 확인
+fn check_metadata_keys(content: &str, body: &str, issues: &mut Vec<LintIssue>) {
+    let front_matter = &content[..content.len() - body.len()];
+    let Some(table) = front_matter
+        .lines()
+        .filter(|line| line.trim_end() != "+++")
+        .collect::<Vec<_>>()
+        .join("\n")
+        .parse::<toml::Table>()
+        .ok()
+    else {
+        return;
+    };
+
+    for key in table.keys() {
+        if !KNOWN_METADATA_KEYS.contains(&key.as_str()) {
+            let line = front_matter
+                .lines()
+                .position(|line| {
+                    line.trim_start()
+                        .strip_prefix(key.as_str())
+                        .is_some_and(|rest| rest.trim_start().starts_with('='))
+                })
+                .map(|i| i + 1);
+            issues.push(LintIssue::new(
+                Severity::Warning,
+                "front-matter",
+                line,
+                t!("lint.unknown_key", key, KNOWN_METADATA_KEYS.join(", ")),
+            ));
+        }
+    }
+}
+
+/// 본문의 태그
+struct Tag {
+    /// 줄 번호 (파일 전체 기준)
+    line: usize,
+    /// `{{`가 시작하는 열
+    column: usize,
+    /// 앞뒤 공백을 제외한 태그 내용
+    text: String,
+}
+
+impl Tag {
+    /// include 대상 (`{{> 이름 }}`)
+    fn include(&self) -> Option<&str> {
+        self.text.strip_prefix('>').map(str::trim)
+    }
+}
+
+/// 본문의 태그 목록과 닫히지 않은 태그의 위치 (코드 블록 제외)
+fn scan_tags(body: &str, body_offset: usize) -> (Vec<Tag>, Option<(usize, usize)>) {
+    let blocks = render::literal_ranges(body);
+    let mut tags = Vec::new();
+    let mut rest = body;
+    // 파일 전체 기준 줄 번호와 현재 줄의 본문 내 시작 위치
+    let mut line = body_offset + 1;
+    let mut line_start = 0;
+
+    // 본문 위치까지 줄 번호와 줄 시작 위치 이동
+    let mut advance = |line: &mut usize, from: usize, to: usize| {
+        for (i, _) in body[from..to].match_indices('\n') {
+            *line += 1;
+            line_start = from + i + 1;
+        }
+        line_start
+    };
+
+    while let Some(start) = rest.find("{{") {
+        let offset = body.len() - rest.len();
+        if let Some(end) = render::code_block_end(&blocks, offset + start) {
+            advance(&mut line, offset, end);
+            rest = &body[end..];
+            continue;
+        }
+        let tag_start = offset + start;
+        let line_begin = advance(&mut line, offset, tag_start);
+        let after = &rest[start + 2..];
+
+        if rest[..start].ends_with('\\') {
+            rest = after;
+            continue;
+        }
+        let column = body[line_begin..tag_start].chars().count() + 1;
+        let Some(end) = after.find("}}") else {
+            return (tags, Some((line, column)));
+        };
+
+        tags.push(Tag {
+            line,
+            column,
+            text: after[..end].trim().to_string(),
+        });
+        let tag_end = tag_start + 2 + end + 2;
+        advance(&mut line, tag_start, tag_end);
+        rest = &body[tag_end..];
+    }
+
+    (tags, None)
+}
+
+/// `{{#if}}`, `{{else}}`, `{{/if}}` 짝 확인
+fn check_conditionals(tags: &[Tag], issues: &mut Vec<LintIssue>) {
+    // (여는 태그, else 사용 여부)
+    let mut open: Vec<(&Tag, bool)> = Vec::new();
+    let issue = |tag: &Tag, message: String| {
+        LintIssue::new(Severity::Error, "unbalanced-conditional", Some(tag.line), message)
+            .at_column(Some(tag.column))
+    };
+
+    for tag in tags {
+        if tag.text.starts_with("#if ") {
+            open.push((tag, false));
+        } else if tag.text == "else" {
+            match open.last_mut() {
+                Some((_, seen_else @ false)) => *seen_else = true,
+                Some(_) => issues.push(issue(tag, t!("lint.duplicate_else").to_string())),
+                None => issues.push(issue(tag, t!("render.else_without_if").to_string())),
+            }
+        } else if tag.text == "/if" && open.pop().is_none() {
+            issues.push(issue(tag, t!("render.endif_without_if").to_string()));
+        }
+    }
+
+    for (tag, _) in open {
+        issues.push(issue(tag, t!("render.if_unclosed", "{{#if}}")));
+    }
+}
+
+/// include, extends 대상 확인
+fn check_reference(
+    reference: &str,
+    tag: Option<&Tag>,
+    config: Option<&Config>,
+    missing: Severity,
+    issues: &mut Vec<LintIssue>,
+) {
+    let (line, column) = (tag.map(|tag| tag.line), tag.map(|tag| tag.column));
+    let spec = match TemplateSpec::parse(reference) {
+        Ok(spec) => spec,
+        Err(e) => {
+            issues.push(
+                LintIssue::new(
+                    Severity::Error,
+                    "broken-include",
+                    line,
+                    format!("{} ({:#})", t!("render.invalid_reference", reference), e),
+                )
+                .at_column(column),
+            );
+            return;
+        }
+    };
+
+    if let Some(config) = config {
+        if template::resolve_template(&spec, config).is_err() {
+            issues.push(
+                LintIssue::new(
+                    missing,
+                    "broken-include",
+                    line,
+                    t!("lint.missing_reference", reference),
+                )
+                .at_column(column),
+            );
+        }
+    }
+}
+
+/// 마크다운 제목 구조와 중복 제목 확인
+fn check_headings(body: &str, body_offset: usize, issues: &mut Vec<LintIssue>) {
+    let blocks = render::code_blocks(body);
+    let mut offset = 0;
+    let mut previous_level = 0;
+    // 상위 제목별로 이미 사용된 하위 제목 (레벨, 제목 목록)
+    let mut sections: Vec<(usize, BTreeMap<String, usize>)> = vec![(0, BTreeMap::new())];
+
+    for (i, line) in body.split_inclusive('\n').enumerate() {
+        let line_number = body_offset + i + 1;
+        let start = offset;
+        offset += line.len();
+        let line = line.trim_end_matches(['\n', '\r']);
+        let trimmed = line.trim_start();
+
+        // 펜스 코드 블록(펜스 줄 포함)과 들여쓴 코드는 제목이 아님
+        if render::code_block_end(&blocks, start).is_some() || line.starts_with("    ") {
+            continue;
+        }
+
+        let level = trimmed.chars().take_while(|&c| c == '#').count();
+        if level == 0 || level > 6 {
+            continue;
+        }
+        let rest = &trimmed[level..];
+        if !rest.is_empty() && !rest.starts_with(' ') {
+            continue;
+        }
+        let title = rest.trim().trim_end_matches('#').trim().to_string();
+
+        if title.is_empty() {
+            issues.push(LintIssue::new(
+                Severity::Warning,
+                "heading-structure",
+                Some(line_number),
+                t!("lint.empty_heading").to_string(),
+            ));
+            continue;
+        }
+
+        if previous_level > 0 && level > previous_level + 1 {
+            issues.push(LintIssue::new(
+                Severity::Warning,
+                "heading-structure",
+                Some(line_number),
+                t!("lint.heading_skip", previous_level, level, title),
+            ));
+        }
+        previous_level = level;
+
+        while sections.last().is_some_and(|(l, _)| *l >= level) {
+            sections.pop();
+        }
+        if let Some((_, siblings)) = sections.last_mut() {
+            if let Some(first) = siblings.get(&title) {
+                issues.push(LintIssue::new(
+                    Severity::Warning,
+                    "duplicate-heading",
+                    Some(line_number),
+                    t!("lint.duplicate_heading", title, first),
+                ));
+            } else {
+                siblings.insert(title, line_number);
+            }
+        }
+        sections.push((level, BTreeMap::new()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn rules(issues: &[LintIssue]) -> Vec<&'static str> {
+        issues.iter().map(|i| i.rule).collect()
+    }
+
+    #[test]
+    fn test_clean_template() {
+        let content = "+++\ndescription = \"팀 규칙\"\n[variables]\nteam = \"Core\"\n+++\n# {{ team }}\n## 규칙\n{{#if strict}}strict{{/if}}\n";
+        assert!(lint_content(content, None).is_empty());
+    }
+
+    #[test]
+    fn test_reports_problems_with_lines() {
+        let content = "+++\nauthor = \"x\"\n[variables]\nunused = \"1\"\n+++\n# Title\n### Skipped\n## A\n## A\n{{ missing }}\n";
+        let issues = lint_content(content, None);
+
+        // 위치가 없는 문제가 먼저, 나머지는 줄 순서
+        assert_eq!(
+            rules(&issues),
+            vec![
+                "unused-variable",
+                "front-matter",
+                "heading-structure",
+                "duplicate-heading",
+                "undefined-variable"
+            ]
+        );
+        let lines: Vec<Option<usize>> = issues.iter().map(|i| i.line).collect();
+        assert_eq!(lines, vec![None, Some(2), Some(7), Some(9), Some(10)]);
+        assert_eq!(issues[4].column, Some(1));
+        assert!(issues.iter().all(|i| i.severity == Severity::Warning));
+    }
+
+    #[test]
+    fn test_reports_errors() {
+        let issues = lint_content("# A\n{{#if a}}\n{{else}}\n{{else}}\n", None);
+        assert_eq!(rules(&issues), vec!["unbalanced-conditional", "unbalanced-conditional"]);
+        assert_eq!(issues[0].line, Some(2));
+        assert_eq!(issues[1].line, Some(4));
+
+        // 조건문 오류가 있어도 나머지 검사를 모두 실행
+        let issues = lint_content("# A\n### B\ntext {{#if a}}\n{{ b }} {{ x y }}\n", None);
+        assert_eq!(
+            rules(&issues),
+            vec!["heading-structure", "unbalanced-conditional", "undefined-variable", "unknown-tag"]
+        );
+        assert_eq!(issues[1].line, Some(3));
+        assert_eq!(issues[1].column, Some(6));
+        assert_eq!(issues[3].column, Some(9));
+        assert_eq!(issues[1].to_string().split(' ').next(), Some("3:6:error"));
+
+        // 닫히지 않은 태그와 잘못된 프론트 매터 내용도 다른 문제와 함께 보고
+        let issues = lint_content("+++\ntags = \"x\"\n+++\n# A\n## A\n## A\n{{ open\n", None);
+        assert_eq!(rules(&issues), vec!["front-matter", "duplicate-heading", "syntax"]);
+        assert_eq!(issues[2].line, Some(7));
+
+        let issues = lint_content("+++\ntags = \"x\"\n+++\n", None);
+        assert_eq!(rules(&issues), vec!["front-matter"]);
+        assert_eq!(issues[0].severity, Severity::Error);
+
+        // 코드 블록 안의 제목과 태그는 무시
+        let issues = lint_content("# A\n```\n### code\n{{ x }}\n```\n{{> ../x }}\n", None);
+        assert_eq!(rules(&issues), vec!["broken-include"]);
+        // 더 긴 펜스 안의 ```는 블록을 닫지 않음
+        let issues = lint_content("# A\n````\n```\n### code\n````\n## B\n", None);
+        assert!(issues.is_empty());
+
+        // 이름이 같은 접두사로 시작하는 키와 구분해서 줄 번호 표시
+        let issues = lint_content("+++\ntags = [\"a\"]\ntag = \"x\"\n+++\n# A\n", None);
+        assert_eq!(rules(&issues), vec!["front-matter"]);
+        assert_eq!(issues[0].line, Some(3));
+
+        // 알 수 없는 태그는 그대로 출력되므로 경고, 인라인 코드 안은 무시
+        let issues = lint_content("# A\n`{{ a b }}`\n{{ a b }}\n", None);
+        assert_eq!(rules(&issues), vec!["unknown-tag"]);
+        assert_eq!(issues[0].line, Some(3));
+        assert_eq!(issues[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_lint_template_checks_references() {
+        let temp_dir = tempdir().unwrap();
+        let config = Config::new(temp_dir.path().to_path_buf());
+
+        fs::write(temp_dir.path().join("Footer.md"), "footer").unwrap();
+        fs::write(temp_dir.path().join("Rules.md"), "# Rules\n{{> Footer }}\n{{> Missing }}\n").unwrap();
+
+        let report = lint_template(&TemplateSpec::parse("Rules").unwrap(), &config).unwrap();
+        assert!(report.has_errors());
+        assert_eq!(rules(&report.issues), vec!["broken-include"]);
+        assert_eq!(report.issues[0].line, Some(3));
+    }
+}
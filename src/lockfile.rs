@@ -0,0 +1,390 @@
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::backup;
+use crate::config::Config;
+use crate::error::{Error, Result};
+use crate::i18n::t;
+use crate::name::TemplateSpec;
+use crate::render;
+use crate::template;
+
+/// 락파일 이름 (프로젝트 루트 기준)
+pub const LOCKFILE_NAME: &str = ".initai.lock";
+
+/// 락파일 첫 줄 주석 (팀이 함께 커밋하는 파일이라 메시지 언어와 관계없이 영어로 고정)
+pub const LOCKFILE_HEADER: &str = "# Generated by initai. Do not edit by hand.";
+
+/// 현재 락파일 형식 버전
+const LOCKFILE_VERSION: u32 = 1;
+
+/// 프로젝트 락파일 (init으로 생성한 파일과 생성 당시의 템플릿 정보)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Lockfile {
+    pub version: u32,
+    #[serde(default, rename = "file")]
+    pub files: Vec<LockedFile>,
+}
+
+/// 락파일에 기록된 생성 파일
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LockedFile {
+    /// 프로젝트 루트 기준 경로
+    pub path: PathBuf,
+    /// 생성에 사용한 템플릿 (사용한 파일로 고정, `이름@버전` 또는 버전 없는 파일은 `이름@`)
+    pub template: String,
+    /// 생성 시각
+    pub generated_at: String,
+    /// 생성된 내용의 해시
+    pub content_hash: String,
+    /// 생성 당시 템플릿 원본의 해시
+    pub template_hash: String,
+    /// 생성에 사용한 번역본 로케일 (기본 파일을 사용했으면 None)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    /// 생성에 사용한 변수
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub variables: BTreeMap<String, String>,
+    /// 백업에서 복원한 내용의 해시 (복원한 뒤에는 직접 수정 여부를 이 해시와 비교)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restored_hash: Option<String>,
+}
+
+impl Default for Lockfile {
+    fn default() -> Self {
+        Self {
+            version: LOCKFILE_VERSION,
+            files: Vec::new(),
+        }
+    }
+}
+
+impl Lockfile {
+    /// 프로젝트의 락파일 읽기 (없으면 None)
+    pub fn load(root: &Path) -> Result<Option<Self>> {
+        let path = root.join(LOCKFILE_NAME);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| t!("lockfile.read_failed", path.display()))?;
+        let lockfile: Lockfile = toml::from_str(&content)
+            .with_context(|| t!("lockfile.invalid", path.display()))?;
+
+        if lockfile.version > LOCKFILE_VERSION {
+            return Err(Error::Validation(t!("lockfile.unsupported_version", lockfile.version)));
+        }
+
+        Ok(Some(lockfile))
+    }
+
+    /// 락파일 내용 직렬화
+    pub fn to_toml(&self) -> Result<String> {
+        let body = toml::to_string_pretty(self).context(t!("lockfile.serialize_failed"))?;
+        Ok(format!("{}\n{}", LOCKFILE_HEADER, body))
+    }
+
+    /// 프로젝트에 락파일 저장
+    pub fn save(&self, root: &Path) -> Result<()> {
+        backup::write_atomic(&root.join(LOCKFILE_NAME), self.to_toml()?)
+    }
+
+    /// 생성 파일을 백업에서 복원한 뒤 복원한 내용 기록
+    ///
+    /// 복원한 파일이 직접 수정된 것으로 보이지 않도록 복원한 내용의 해시를 따로 기록하며,
+    /// 생성 당시의 정보는 그대로 유지. 락파일이 없거나 파일이 기록되어 있지 않으면 아무것도 하지 않음
+    pub fn record_restored(root: &Path, path: &Path, content: &str) -> Result<()> {
+        let Some(mut lock) = Self::load(root)? else {
+            return Ok(());
+        };
+        let Some(entry) = lock.files.iter_mut().find(|f| f.path == path) else {
+            return Ok(());
+        };
+
+        let restored = hash(content);
+        entry.restored_hash = (restored != entry.content_hash).then_some(restored);
+        lock.save(root)
+    }
+
+    /// 같은 경로의 항목을 교체하거나 추가
+    pub fn upsert(&mut self, entry: LockedFile) {
+        match self.files.iter_mut().find(|f| f.path == entry.path) {
+            Some(existing) => *existing = entry,
+            None => self.files.push(entry),
+        }
+    }
+}
+
+/// 내용 해시 (`sha256:<hex>`)
+pub fn hash(content: &str) -> String {
+    format!("sha256:{:x}", Sha256::digest(content.as_bytes()))
+}
+
+/// 템플릿 원본 상태
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemplateState {
+    /// 지금 렌더링해도 같은 내용
+    Unchanged,
+    /// 생성 이후 템플릿(또는 include 대상)이 변경됨
+    Changed,
+    /// 템플릿이 설치되어 있지 않음
+    Missing,
+    /// 렌더링 실패 (오류 메시지)
+    Invalid(String),
+}
+
+impl TemplateState {
+    /// JSON 출력 등에 사용하는 상태 이름
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TemplateState::Unchanged => "unchanged",
+            TemplateState::Changed => "changed",
+            TemplateState::Missing => "missing",
+            TemplateState::Invalid(_) => "invalid",
+        }
+    }
+}
+
+/// 생성 파일의 현재 상태
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileStatus {
+    /// 파일이 존재하는지 여부
+    pub exists: bool,
+    /// 생성 (또는 백업에서 복원한) 이후 파일이 직접 수정되었는지 여부
+    pub modified: bool,
+    /// 생성한 내용 대신 백업에서 복원한 내용이 기록되어 있는지 여부
+    pub restored: bool,
+    pub template: TemplateState,
+    /// 템플릿 원본 파일 자체가 변경되었는지 여부 (include 대상만 바뀐 경우와 구분)
+    pub source_changed: bool,
+    /// 고정된 버전보다 새로 설치된 버전 (`이름@버전`)
+    pub newer_version: Option<String>,
+}
+
+/// 락파일 항목과 현재 프로젝트 파일, 템플릿 비교
+pub fn inspect(root: &Path, entry: &LockedFile, config: &Config) -> FileStatus {
+    let current = fs::read_to_string(root.join(&entry.path)).ok();
+    let exists = current.is_some();
+    let expected = entry.restored_hash.as_ref().unwrap_or(&entry.content_hash);
+    let modified = current.is_some_and(|content| hash(&content) != *expected);
+
+    let mut source_changed = false;
+    let mut newer_version = None;
+
+    let template = match TemplateSpec::parse(&entry.template) {
+        Err(e) => TemplateState::Invalid(format!("{:#}", e)),
+        Ok(spec) => {
+            // 사용한 파일로 고정되어 있으면 (버전 없는 파일 포함) 같은 이름의 최신 버전과 비교
+            if !spec.is_latest() {
+                newer_version = template::resolve_template(&spec.name.clone().into(), config)
+                    .ok()
+                    .filter(|latest| latest.version != spec.version)
+                    .map(|latest| latest.id());
+            }
+
+            let locale = entry.locale.as_deref();
+            match template::get_localized_content(&spec, locale, config) {
+                Err(_) => TemplateState::Missing,
+                Ok(source) => {
+                    source_changed = hash(&source) != entry.template_hash;
+                    match render::render_localized(&spec, locale, &entry.variables, config) {
+                        Ok(rendered) if hash(&rendered) == entry.content_hash => {
+                            TemplateState::Unchanged
+                        }
+                        Ok(_) => TemplateState::Changed,
+                        Err(e) => TemplateState::Invalid(format!("{:#}", e)),
+                    }
+                }
+            }
+        }
+    };
+
+    FileStatus {
+        exists,
+        modified,
+        restored: entry.restored_hash.is_some(),
+        template,
+        source_changed,
+        newer_version,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn entry(content: &str) -> LockedFile {
+        LockedFile {
+            path: PathBuf::from("rules/TEAM_RULES.md"),
+            template: "Rules@1.0".to_string(),
+            generated_at: "2026-01-01 00:00:00".to_string(),
+            content_hash: hash(content),
+            template_hash: hash(content),
+            locale: None,
+            variables: BTreeMap::new(),
+            restored_hash: None,
+        }
+    }
+
+    #[test]
+    fn test_lockfile_roundtrip() {
+        let root = tempdir().unwrap();
+        assert!(Lockfile::load(root.path()).unwrap().is_none());
+
+        let mut lockfile = Lockfile::default();
+        lockfile.upsert(entry("v1"));
+        lockfile.upsert(entry("v2"));
+        assert_eq!(lockfile.files.len(), 1);
+
+        fs::write(root.path().join(LOCKFILE_NAME), lockfile.to_toml().unwrap()).unwrap();
+        let loaded = Lockfile::load(root.path()).unwrap().unwrap();
+        assert_eq!(loaded, lockfile);
+        assert_eq!(loaded.files[0].content_hash, hash("v2"));
+
+        fs::write(root.path().join(LOCKFILE_NAME), "version = \"x\"").unwrap();
+        assert!(Lockfile::load(root.path()).is_err());
+    }
+
+    #[test]
+    fn test_record_restored() {
+        let root = tempdir().unwrap();
+        let path = PathBuf::from("rules/TEAM_RULES.md");
+
+        // 락파일이 없으면 무시
+        Lockfile::record_restored(root.path(), &path, "# Old").unwrap();
+        assert!(!root.path().join(LOCKFILE_NAME).exists());
+
+        let mut lockfile = Lockfile::default();
+        lockfile.upsert(entry("# New"));
+        lockfile.save(root.path()).unwrap();
+
+        // 복원한 내용은 직접 수정으로 보지 않고, 템플릿은 생성 당시와 같음
+        let template_dir = tempdir().unwrap();
+        let config = Config::new(template_dir.path().to_path_buf());
+        fs::write(template_dir.path().join("Rules@1.0.md"), "# New").unwrap();
+        fs::create_dir_all(root.path().join("rules")).unwrap();
+        fs::write(root.path().join(&path), "# Old").unwrap();
+        Lockfile::record_restored(root.path(), &path, "# Old").unwrap();
+        let loaded = Lockfile::load(root.path()).unwrap().unwrap();
+        assert_eq!(loaded.files[0].content_hash, hash("# New"));
+        assert_eq!(loaded.files[0].restored_hash, Some(hash("# Old")));
+
+        let status = inspect(root.path(), &loaded.files[0], &config);
+        assert!(status.restored && !status.modified);
+        assert_eq!(status.template, TemplateState::Unchanged);
+
+        // 생성한 내용으로 다시 복원하면 복원 기록 삭제
+        Lockfile::record_restored(root.path(), &path, "# New").unwrap();
+        let loaded = Lockfile::load(root.path()).unwrap().unwrap();
+        assert_eq!(loaded.files[0].restored_hash, None);
+    }
+
+    #[test]
+    fn test_inspect() {
+        let root = tempdir().unwrap();
+        let template_dir = tempdir().unwrap();
+        let config = Config::new(template_dir.path().to_path_buf());
+        let rules = root.path().join("rules/TEAM_RULES.md");
+        fs::create_dir_all(rules.parent().unwrap()).unwrap();
+
+        let locked = entry("# Rules");
+
+        // 템플릿이 없고 파일도 없음
+        let status = inspect(root.path(), &locked, &config);
+        assert!(!status.exists);
+        assert_eq!(status.template, TemplateState::Missing);
+
+        // 생성 당시와 같음
+        fs::write(template_dir.path().join("Rules@1.0.md"), "# Rules").unwrap();
+        fs::write(&rules, "# Rules").unwrap();
+        let status = inspect(root.path(), &locked, &config);
+        assert!(status.exists && !status.modified);
+        assert_eq!(status.template, TemplateState::Unchanged);
+
+        // 파일과 템플릿이 모두 변경됨
+        fs::write(template_dir.path().join("Rules@1.0.md"), "# Rules v2").unwrap();
+        fs::write(&rules, "# Edited").unwrap();
+        let status = inspect(root.path(), &locked, &config);
+        assert!(status.modified);
+        assert!(status.source_changed);
+        assert_eq!(status.template, TemplateState::Changed);
+        assert_eq!(status.newer_version, None);
+
+        // 새 버전 설치
+        fs::write(template_dir.path().join("Rules@1.1.md"), "# Rules").unwrap();
+        let status = inspect(root.path(), &locked, &config);
+        assert_eq!(status.newer_version.as_deref(), Some("Rules@1.1"));
+    }
+
+    #[test]
+    fn test_inspect_unversioned_with_newer_version() {
+        let root = tempdir().unwrap();
+        let template_dir = tempdir().unwrap();
+        let config = Config::new(template_dir.path().to_path_buf());
+        fs::write(template_dir.path().join("Rules.md"), "# Rules").unwrap();
+        fs::write(root.path().join("TEAM_RULES.md"), "# Rules").unwrap();
+
+        let locked = LockedFile {
+            path: PathBuf::from("TEAM_RULES.md"),
+            template: "Rules@".to_string(),
+            ..entry("# Rules")
+        };
+        assert_eq!(inspect(root.path(), &locked, &config).newer_version, None);
+
+        // 새 버전이 설치되어도 템플릿은 변경되지 않았고 새 버전만 안내
+        fs::write(template_dir.path().join("Rules@2.0.md"), "# Rules v2").unwrap();
+        let status = inspect(root.path(), &locked, &config);
+        assert_eq!(status.template, TemplateState::Unchanged);
+        assert!(!status.source_changed);
+        assert_eq!(status.newer_version.as_deref(), Some("Rules@2.0"));
+    }
+
+    #[test]
+    fn test_status_from_lockfile() {
+        let root = tempdir().unwrap();
+        let template_dir = tempdir().unwrap();
+        let config = Config::new(template_dir.path().to_path_buf());
+
+        // 생성 당시 파일과 템플릿 내용이 같은 항목 세 개 기록
+        let mut lockfile = Lockfile::default();
+        for name in ["Deleted", "Edited", "Updated"] {
+            let content = format!("# {}", name);
+            fs::write(template_dir.path().join(format!("{}.md", name)), &content).unwrap();
+            fs::write(root.path().join(format!("{}.md", name)), &content).unwrap();
+            lockfile.upsert(LockedFile {
+                path: PathBuf::from(format!("{}.md", name)),
+                template: name.to_string(),
+                ..entry(&content)
+            });
+        }
+        fs::write(root.path().join(LOCKFILE_NAME), lockfile.to_toml().unwrap()).unwrap();
+
+        fs::remove_file(root.path().join("Deleted.md")).unwrap();
+        fs::write(root.path().join("Edited.md"), "# Edited by hand").unwrap();
+        fs::write(template_dir.path().join("Updated.md"), "# Updated v2").unwrap();
+
+        let loaded = Lockfile::load(root.path()).unwrap().unwrap();
+        let statuses: Vec<FileStatus> = loaded
+            .files
+            .iter()
+            .map(|entry| inspect(root.path(), entry, &config))
+            .collect();
+
+        // 파일 없음
+        assert!(!statuses[0].exists);
+        assert_eq!(statuses[0].template, TemplateState::Unchanged);
+        // 파일만 직접 수정됨
+        assert!(statuses[1].exists && statuses[1].modified);
+        assert_eq!(statuses[1].template, TemplateState::Unchanged);
+        // 템플릿만 변경됨
+        assert!(statuses[2].exists && !statuses[2].modified);
+        assert!(statuses[2].source_changed);
+        assert_eq!(statuses[2].template, TemplateState::Changed);
+    }
+}
@@ -1,55 +1,192 @@
 mod cli;
-mod config;
-mod embedded;
-mod init;
 mod interactive;
-mod template;
+mod man;
+mod output;
+mod picker;
 
 use anyhow::Result;
-use clap::Parser;
+use clap::{CommandFactory, FromArgMatches};
+use clap_complete::env::Shells;
 use colored::Colorize;
 use dialoguer::Confirm;
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::io::{IsTerminal, Write};
+use std::process::{Command, Stdio};
 
-use cli::{Cli, Commands, TemplateAction};
+use cli::{Cli, Commands, CompletionShell, ConfigAction, TemplateAction};
+use init_ai::i18n::{self, t};
+use init_ai::tokens::{self, TokenEstimate};
+use init_ai::{backup, config, doctor, embedded, error, init, lint, lockfile, name, render, template};
+use init_ai::{Error, TemplateName, TemplateSpec};
+use output::{Output, Reported, Usage};
 
 fn main() {
-    if let Err(e) = run() {
-        eprintln!("{} {}", "✗".red(), e.to_string().red());
-        std::process::exit(1);
+    // 도움말과 인수 오류도 번역되도록 파싱 전에 언어 결정
+    i18n::set_language(detect_language());
+    // 셸이 자동 완성 후보를 요청한 경우 (`COMPLETE=<셸> initai -- ...`) 후보만 출력하고 종료
+    clap_complete::CompleteEnv::with_factory(|| i18n::localize_command(Cli::command())).complete();
+    let matches = i18n::localize_command(Cli::command()).get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    if let Some(lang) = cli.lang {
+        i18n::set_language(lang);
+    }
+
+    let out = Output {
+        format: cli.format,
+        quiet: cli.quiet,
+        yes: cli.yes,
+    };
+
+    let verbose = cli.verbose;
+    if let Err(e) = run(cli, out) {
+        let code = output::exit_code(&e);
+        report_error(&e, code, verbose, out);
+        std::process::exit(code);
     }
 }
 
-fn run() -> Result<()> {
-    let cli = Cli::parse();
+/// 오류 출력 (`--verbose`면 원인 체인 전체 표시)
+fn report_error(e: &anyhow::Error, code: i32, verbose: bool, out: Output) {
+    if out.is_json() {
+        // 결과 JSON을 이미 출력한 경우 표준 출력과 표준 오류에 문서를 두 개 남기지 않음
+        if e.downcast_ref::<Reported>().is_none() {
+            let message = output::chain_messages(e).join(": ");
+            eprintln!("{}", json!({ "error": message, "code": code }));
+        }
+        return;
+    }
+    // 취소 안내는 각 명령에서 이미 출력
+    if code == error::EXIT_CANCELLED {
+        return;
+    }
+
+    eprintln!("{} {}", "✗".red(), e.to_string().red());
+    let causes = output::chain_messages(e);
+    if verbose {
+        for cause in causes.iter().skip(1) {
+            eprintln!("  {}", t!("error.caused_by", cause));
+        }
+    } else if causes.len() > 1 && !out.quiet {
+        eprintln!("  {}", t!("error.verbose_hint").dimmed());
+    }
+}
+
+/// 메시지 언어 결정 (`--lang`, 설정 파일의 language, 환경 변수 순, 모두 없으면 영어)
+fn detect_language() -> i18n::Lang {
+    i18n::from_args(std::env::args_os().skip(1).filter_map(|arg| arg.into_string().ok()))
+        .or_else(|| config::load_config().ok().and_then(|config| config.language))
+        .or_else(i18n::from_env)
+        .unwrap_or_default()
+}
 
+fn run(cli: Cli, out: Output) -> Result<()> {
     match cli.command {
         None => {
             // 인수 없이 실행 시 대화형 모드
-            interactive::run_interactive_mode()?;
+            interactive::run_interactive_mode(&BTreeMap::new(), None, out)?;
         }
-        Some(Commands::List) => {
-            handle_list()?;
+        Some(Commands::List { all_versions, long }) => {
+            handle_list(all_versions, long, out)?;
         }
         Some(Commands::Init {
             template,
             template_flag,
+            vars,
+            locale,
         }) => {
             let template_name = template.or(template_flag);
-            handle_init(template_name)?;
+            handle_init(template_name, &vars, locale, out)?;
+        }
+        Some(Commands::Status) => {
+            handle_status(out)?;
+        }
+        Some(Commands::Check) => {
+            handle_check(out)?;
+        }
+        Some(Commands::Doctor) => {
+            handle_doctor(out)?;
+        }
+        Some(Commands::Restore { backup, list }) => {
+            if list {
+                handle_restore_list(out)?;
+            } else {
+                handle_restore(backup.as_deref(), out)?;
+            }
         }
         Some(Commands::Template { action }) => match action {
-            TemplateAction::Install { file_path, name } => {
-                handle_template_install(&file_path, name)?;
+            TemplateAction::List { all_versions, long } => {
+                handle_list(all_versions, long, out)?;
             }
+            TemplateAction::Install {
+                file_path,
+                name,
+                builtin,
+                all: _,
+            } => match file_path {
+                Some(file_path) => handle_template_install(&file_path, name, out)?,
+                // 파일 경로가 없으면 --builtin 또는 --all이 지정된 경우
+                None => handle_builtin_install(builtin.as_deref(), out)?,
+            },
             TemplateAction::Remove { template_name } => {
-                handle_template_remove(&template_name)?;
+                handle_template_remove(&template_name, out)?;
+            }
+            TemplateAction::New {
+                template_name,
+                from,
+            } => {
+                handle_template_new(&template_name, from.as_ref(), out)?;
+            }
+            TemplateAction::Edit { template_name } => {
+                handle_template_edit(&template_name, out)?;
+            }
+            TemplateAction::Rename { old_name, new_name } => {
+                handle_template_rename(&old_name, &new_name, out)?;
+            }
+            TemplateAction::Copy {
+                source,
+                destination,
+            } => {
+                handle_template_copy(&source, &destination, out)?;
+            }
+            TemplateAction::Show { template_name } => {
+                handle_template_show(&template_name, out)?;
+            }
+            TemplateAction::Preview {
+                template_name,
+                vars,
+            } => {
+                handle_template_preview(&template_name, &vars, out)?;
+            }
+            TemplateAction::Lint { target, file } => {
+                handle_template_lint(target.as_ref(), file.as_deref(), out)?;
+            }
+            TemplateAction::Reset { template_name } => {
+                handle_template_reset(&template_name, out)?;
+            }
+            TemplateAction::Prune {
+                template_name,
+                keep,
+            } => {
+                handle_template_prune(template_name.as_ref(), keep, out)?;
             }
         },
-        Some(Commands::Config { set_template_path }) => {
+        Some(Commands::Completions { shell }) => {
+            handle_completions(shell)?;
+        }
+        Some(Commands::Man { out_dir, markdown }) => {
+            handle_man(out_dir, markdown, out)?;
+        }
+        Some(Commands::Config {
+            set_template_path,
+            action,
+        }) => {
             if let Some(path) = set_template_path {
-                handle_config_set_path(path)?;
+                handle_config_set_path(path, out)?;
+            } else if let Some(ConfigAction::List) = action {
+                handle_config_list(out)?;
             } else {
-                println!("{}", "사용법: initai config --set-template-path <경로>".yellow());
+                return Err(Usage(t!("config.usage").to_string()).into());
             }
         }
     }
@@ -58,59 +195,495 @@ fn run() -> Result<()> {
 }
 
 /// list 명령 처리
-fn handle_list() -> Result<()> {
-    let config = config::load_config()?;
-    let templates = template::list_templates(&config)?;
+fn handle_list(all_versions: bool, long: bool, out: Output) -> Result<()> {
+    let config = config::load_or_builtin()?;
+    let templates = if all_versions {
+        template::list_template_versions(&config)?
+    } else {
+        template::list_templates(&config)?
+    };
+    let skipped = template::skipped_files(&config)?;
+
+    if out.is_json() {
+        let mut entries = Vec::new();
+        for template in &templates {
+            let mut entry = json!({
+                "id": template.id(),
+                "name": template.name.as_str(),
+                "version": template.version,
+                "path": template::installed_path(template, &config)?,
+                "locales": template::list_locales(template, &config)?,
+                "modified_builtin": template::is_modified_builtin(template, &config)?,
+            });
+            if long {
+                let (metadata, estimate) = template_details(template, &config)?;
+                entry["description"] = json!(metadata.description);
+                entry["tags"] = json!(metadata.tags);
+                entry["tokens"] = json!(estimate);
+                entry["max_tokens"] =
+                    json!(tokens::effective_limit(metadata.max_tokens, config.max_tokens));
+            }
+            entries.push(entry);
+        }
+        let skipped: Vec<_> = skipped
+            .iter()
+            .map(|file| json!({ "path": file.path, "reason": file.reason }))
+            .collect();
+        return out.json(&json!({
+            "templates": entries,
+            "skipped": skipped,
+            "builtin_only": config.builtin_only,
+        }));
+    }
+
+    for file in &skipped {
+        out.warn(t!("list.skipped_file", file.path.display(), file.reason).yellow());
+    }
 
     if templates.is_empty() {
-        println!(
-            "{} 사용 가능한 템플릿이 없습니다.",
-            "⚠️".yellow()
-        );
-        println!("'initai template install' 명령으로 템플릿을 추가하세요.");
+        out.info(format!("{} {}", "⚠️".yellow(), t!("common.no_templates")));
+        out.info(t!("common.install_hint"));
+    } else if out.quiet {
+        // 스크립트에서 쓰기 쉽도록 이름만 한 줄씩 출력
+        for template in &templates {
+            println!("{}", template.id());
+        }
     } else {
-        println!("{}", "사용 가능한 템플릿:".cyan());
+        println!("{}", t!("common.available_templates").cyan());
+        let mut any_modified = false;
         for (i, template) in templates.iter().enumerate() {
-            println!("  {}. {}", i + 1, template.name.cyan());
+            let mut line = format!("  {}. {}", i + 1, template.id().cyan());
+            let locales = template::list_locales(template, &config)?;
+            if !locales.is_empty() {
+                line.push_str(&format!(" {}", format!("[{}]", locales.join(", ")).dimmed()));
+            }
+            if template::is_modified_builtin(template, &config)? {
+                line.push_str(&format!(" {}", t!("list.modified_builtin").yellow()));
+                any_modified = true;
+            }
+            println!("{}", line);
+            if long {
+                print_template_details(template, &config)?;
+            }
+        }
+        if any_modified {
+            out.info(t!("list.reset_hint").dimmed());
+        }
+        if config.builtin_only {
+            out.info(t!("list.builtin_only_hint").dimmed());
         }
     }
 
     Ok(())
 }
 
+/// 템플릿 메타데이터와 기본값으로 렌더링한 결과의 추정 토큰 수
+fn template_details(
+    template: &template::Template,
+    config: &config::Config,
+) -> Result<(render::TemplateMetadata, TokenEstimate)> {
+    let content = template::get_template_content(&template.spec(), config)?;
+    let mut metadata = render::split_front_matter(&content)
+        .map(|(metadata, _)| metadata)
+        .unwrap_or_default();
+
+    // 토큰 한도는 extends 대상에서 상속 (대상을 읽을 수 없으면 직접 지정한 값만 사용)
+    let store = config.store();
+    if let Ok(max_tokens) = render::Renderer::new(store.as_ref()).max_tokens(&metadata) {
+        metadata.max_tokens = max_tokens;
+    }
+
+    // 렌더링할 수 없으면 (필수 변수 누락 등) 원본 내용으로 추정
+    let rendered = render::render_template(&template.spec(), &BTreeMap::new(), config);
+    let estimate = TokenEstimate::of(rendered.as_deref().unwrap_or(&content));
+
+    Ok((metadata, estimate))
+}
+
+/// list --long 상세 정보 출력 (설명, 추정 토큰 수)
+fn print_template_details(template: &template::Template, config: &config::Config) -> Result<()> {
+    let (metadata, estimate) = template_details(template, config)?;
+
+    if let Some(description) = metadata.description.as_deref().filter(|d| !d.is_empty()) {
+        println!("     {}", description);
+    }
+
+    let limit = tokens::effective_limit(metadata.max_tokens, config.max_tokens);
+    match limit {
+        Some(limit) if estimate.max() > limit => println!(
+            "     {} {}",
+            estimate,
+            t!("list.limit_exceeded", limit).red()
+        ),
+        Some(limit) => println!("     {} {}", estimate, t!("list.limit", limit)),
+        None => println!("     {}", estimate),
+    }
+
+    Ok(())
+}
+
 /// init 명령 처리
-fn handle_init(template_name: Option<String>) -> Result<()> {
-    let config = config::load_config()?;
+fn handle_init(
+    template_name: Option<TemplateSpec>,
+    vars: &[String],
+    locale: Option<String>,
+    out: Output,
+) -> Result<()> {
+    let config = config::load_or_builtin()?;
+    let variables = parse_variables(vars)?;
 
     // 템플릿 이름 결정
     let template_name = if let Some(name) = template_name {
         name
     } else {
-        // 템플릿이 지정되지 않았으면 대화형 모드로 선택
-        return interactive::run_interactive_mode();
+        // 템플릿이 지정되지 않았으면 대화형 모드로 선택 (지정한 변수와 로케일은 그대로 사용)
+        return interactive::run_interactive_mode(&variables, locale.as_deref(), out);
+    };
+    let locale = locale.or_else(|| config.locale.clone());
+
+    // 프로젝트 초기화 (기존 파일을 덮어쓰는 경우 확인)
+    let Some(plan) = init::init_project(
+        &template_name,
+        locale.as_deref(),
+        &variables,
+        &config,
+        |conflicts| Ok(interactive::confirm_overwrite(conflicts, out)?),
+    )?
+    else {
+        if out.is_json() {
+            out.json(&json!({ "cancelled": true, "files": [], "warnings": [] }))?;
+        } else {
+            out.info(t!("common.cancelled").yellow());
+        }
+        return Err(out.fail(Error::Cancelled));
+    };
+
+    if out.is_json() {
+        let files: Vec<_> = plan
+            .outputs()
+            .map(|op| {
+                json!({
+                    "path": op.path,
+                    "template": plan.template,
+                    "locale": plan.locale,
+                    "tokens": TokenEstimate::of(&op.content),
+                })
+            })
+            .collect();
+        return out.json(&json!({
+            "cancelled": false,
+            "files": files,
+            "warnings": plan.warnings,
+        }));
+    }
+
+    for op in plan.outputs() {
+        out.info(format!(
+            "{} {}",
+            "✓".green(),
+            t!("common.file_created", op.path.display(), plan.template.cyan())
+        ));
+        out.info(format!(
+            "  {} {}",
+            t!("common.estimated_tokens").cyan(),
+            TokenEstimate::of(&op.content)
+        ));
+    }
+    for warning in &plan.warnings {
+        out.warn(warning.yellow());
+    }
+
+    Ok(())
+}
+
+/// status 명령 처리
+fn handle_status(out: Output) -> Result<()> {
+    let config = config::load_or_builtin()?;
+    let root = std::path::Path::new(".");
+
+    let Some(lock) = lockfile::Lockfile::load(root)? else {
+        if out.is_json() {
+            return out.json(&json!({ "initialized": false, "files": [] }));
+        }
+        out.info(t!("status.not_initialized").yellow());
+        out.info(t!("status.init_hint"));
+        return Ok(());
     };
 
-    // 파일 존재 시 덮어쓰기 확인
-    if init::team_rules_exists() {
-        let overwrite = Confirm::new()
-            .with_prompt("rules/TEAM_RULES.md 파일이 이미 존재합니다. 덮어쓰시겠습니까?")
-            .default(false)
-            .interact()?;
+    if out.is_json() {
+        let files: Vec<_> = lock
+            .files
+            .iter()
+            .map(|entry| {
+                let status = lockfile::inspect(root, entry, &config);
+                let error = match &status.template {
+                    lockfile::TemplateState::Invalid(e) => Some(e.clone()),
+                    _ => None,
+                };
+                json!({
+                    "path": entry.path,
+                    "template": entry.template,
+                    "locale": entry.locale,
+                    "generated_at": entry.generated_at,
+                    "variables": entry.variables,
+                    "exists": status.exists,
+                    "modified": status.modified,
+                    "restored": status.restored,
+                    "template_state": status.template.as_str(),
+                    "template_error": error,
+                    "source_changed": status.source_changed,
+                    "newer_version": status.newer_version,
+                })
+            })
+            .collect();
+        return out.json(&json!({ "initialized": true, "files": files }));
+    }
+
+    if lock.files.is_empty() {
+        out.info(t!("status.no_files").yellow());
+        return Ok(());
+    }
+
+    for (i, entry) in lock.files.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        let status = lockfile::inspect(root, entry, &config);
+
+        println!("{}", entry.path.display().to_string().cyan().bold());
+        println!("  {} {}", t!("status.template").cyan(), entry.template);
+        if let Some(locale) = &entry.locale {
+            println!("  {} {}", t!("status.locale").cyan(), locale);
+        }
+        println!("  {} {}", t!("status.generated_at").cyan(), entry.generated_at);
+        if !entry.variables.is_empty() {
+            let variables: Vec<String> = entry
+                .variables
+                .iter()
+                .map(|(key, value)| format!("{}={}", key, value))
+                .collect();
+            println!("  {} {}", t!("status.variables").cyan(), variables.join(", "));
+        }
+
+        let file_state = if !status.exists {
+            t!("status.file_missing").red()
+        } else if status.modified {
+            t!("status.file_modified").yellow()
+        } else if status.restored {
+            t!("status.file_restored").yellow()
+        } else {
+            t!("status.file_unmodified").green()
+        };
+        println!("  {} {}", t!("status.file").cyan(), file_state);
+
+        let template_state = match &status.template {
+            lockfile::TemplateState::Unchanged => t!("status.template_unchanged").green(),
+            lockfile::TemplateState::Changed if status.source_changed => {
+                t!("status.template_changed").yellow()
+            }
+            lockfile::TemplateState::Changed => t!("status.include_changed").yellow(),
+            lockfile::TemplateState::Missing => t!("status.template_missing").red(),
+            lockfile::TemplateState::Invalid(e) => t!("status.template_invalid", e).red(),
+        };
+        println!("  {} {}", t!("status.source").cyan(), template_state);
+
+        if let Some(latest) = &status.newer_version {
+            println!(
+                "  {} {} {}",
+                t!("status.newer_version").cyan(),
+                latest,
+                t!("status.update_hint", latest)
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// check 명령 처리
+fn handle_check(out: Output) -> Result<()> {
+    let config = config::load_or_builtin()?;
+    let drifts = init::check_project(std::path::Path::new("."), &config)?;
+
+    // 현재 파일 대비 생성 결과의 unified diff
+    let unified = |drift: &init::Drift, actual: &str| {
+        unified_diff(&drift.path.display().to_string(), actual, &drift.expected)
+    };
 
-        if !overwrite {
-            println!("{}", "작업이 취소되었습니다.".yellow());
+    if out.is_json() {
+        let files: Vec<_> = drifts
+            .iter()
+            .map(|drift| {
+                json!({
+                    "path": drift.path,
+                    "missing": drift.actual.is_none(),
+                    "diff": drift.actual.as_deref().map(|actual| unified(drift, actual)),
+                })
+            })
+            .collect();
+        out.json(&json!({ "clean": drifts.is_empty(), "files": files }))?;
+        if drifts.is_empty() {
             return Ok(());
         }
+        return Err(out.fail(Error::Validation(t!("check.failed", drifts.len()))));
     }
 
-    // 프로젝트 초기화
-    init::init_project(&template_name, &config)?;
+    if drifts.is_empty() {
+        out.info(format!("{} {}", "✓".green(), t!("check.clean")));
+        return Ok(());
+    }
 
-    println!(
-        "{} rules/TEAM_RULES.md 파일이 생성되었습니다. (템플릿: {})",
+    for drift in &drifts {
+        let path = drift.path.display().to_string();
+        let Some(actual) = &drift.actual else {
+            println!("{} {}", "✗".red(), t!("check.missing", path));
+            continue;
+        };
+
+        println!("{} {}", "✗".red(), t!("check.differs", path));
+        print_diff(&unified(drift, actual));
+    }
+
+    anyhow::bail!(Error::Validation(t!("check.failed", drifts.len())));
+}
+
+/// 두 내용의 unified diff (`a/경로`, `b/경로` 헤더)
+fn unified_diff(path: &str, old: &str, new: &str) -> String {
+    similar::TextDiff::from_lines(old, new)
+        .unified_diff()
+        .header(&format!("a/{}", path), &format!("b/{}", path))
+        .to_string()
+}
+
+/// unified diff를 색상과 함께 출력
+fn print_diff(diff: &str) {
+    for line in diff.lines() {
+        if line.starts_with('+') && !line.starts_with("+++") {
+            println!("{}", line.green());
+        } else if line.starts_with('-') && !line.starts_with("---") {
+            println!("{}", line.red());
+        } else if line.starts_with("@@") {
+            println!("{}", line.cyan());
+        } else {
+            println!("{}", line);
+        }
+    }
+}
+
+/// doctor 명령 처리
+fn handle_doctor(out: Output) -> Result<()> {
+    let findings = doctor::run(std::path::Path::new("."))?;
+    let errors = findings
+        .iter()
+        .filter(|f| f.status == doctor::Status::Error)
+        .count();
+    let warnings = findings
+        .iter()
+        .filter(|f| f.status == doctor::Status::Warning)
+        .count();
+
+    if out.is_json() {
+        out.json(&json!({
+            "findings": findings,
+            "errors": errors,
+            "warnings": warnings,
+        }))?;
+        if errors > 0 {
+            return Err(out.fail(Error::Validation(t!("doctor.failed", errors, warnings))));
+        }
+        return Ok(());
+    }
+
+    for finding in findings
+        .iter()
+        .filter(|f| !(out.quiet && f.status == doctor::Status::Ok))
+    {
+        let icon = match finding.status {
+            doctor::Status::Ok => "✓".green(),
+            doctor::Status::Warning => "⚠️".yellow(),
+            doctor::Status::Error => "✗".red(),
+        };
+        println!("{} {}: {}", icon, finding.check.cyan(), finding.message);
+        if let Some(fix) = &finding.fix {
+            println!("    → {}", fix);
+        }
+    }
+
+    out.info("");
+    if errors > 0 {
+        anyhow::bail!(Error::Validation(t!("doctor.failed", errors, warnings)));
+    } else if warnings > 0 {
+        out.info(format!("{} {}", "⚠️".yellow(), t!("doctor.warnings", warnings)));
+    } else {
+        out.info(format!("{} {}", "✓".green(), t!("doctor.ok")));
+    }
+
+    Ok(())
+}
+
+/// restore --list 명령 처리
+fn handle_restore_list(out: Output) -> Result<()> {
+    let backups = backup::list_backups(&init::team_rules_path())?;
+
+    if out.is_json() {
+        let entries: Vec<_> = backups
+            .iter()
+            .map(|backup| {
+                json!({
+                    "id": backup.id,
+                    "created_at": backup.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                    "path": backup.path,
+                })
+            })
+            .collect();
+        return out.json(&json!({ "backups": entries }));
+    }
+
+    if backups.is_empty() {
+        out.info(t!("restore.no_backups").yellow());
+        return Ok(());
+    }
+
+    out.info(t!("restore.list_header").cyan());
+    for (i, backup) in backups.iter().enumerate() {
+        println!(
+            "  {}. {} ({})",
+            i + 1,
+            backup.id.cyan(),
+            backup.created_at.format("%Y-%m-%d %H:%M:%S")
+        );
+    }
+
+    Ok(())
+}
+
+/// restore 명령 처리
+fn handle_restore(backup_id: Option<&str>, out: Output) -> Result<()> {
+    let target = init::team_rules_path();
+
+    // 복원할 백업이 있는지 먼저 확인
+    let restored = backup::find_backup(&target, backup_id)?;
+
+    let confirm = out.confirm(&t!("restore.confirm", restored.id), false)?;
+
+    if !confirm {
+        out.info(t!("common.cancelled").yellow());
+        return Err(Error::Cancelled.into());
+    }
+
+    let content = backup::restore_from(&target, &restored)?;
+    // 복원한 파일이 직접 수정된 것으로 보고되지 않도록 락파일 갱신
+    lockfile::Lockfile::record_restored(std::path::Path::new("."), &target, &content)?;
+
+    if out.is_json() {
+        return out.json(&json!({ "restored": restored.id, "path": target }));
+    }
+
+    out.info(format!(
+        "{} {}",
         "✓".green(),
-        template_name.cyan()
-    );
+        t!("restore.done", restored.id.cyan())
+    ));
 
     Ok(())
 }
@@ -118,81 +691,728 @@ fn handle_init(template_name: Option<String>) -> Result<()> {
 /// template install 명령 처리
 fn handle_template_install(
     file_path: &std::path::Path,
-    name: Option<String>,
+    name: Option<(TemplateSpec, Option<String>)>,
+    out: Output,
 ) -> Result<()> {
-    let config = config::load_config()?;
-
-    // 템플릿 이름 결정 (지정되지 않았으면 파일명 사용)
-    let template_name = if let Some(name) = name {
+    // 템플릿 이름과 번역본 로케일 결정 (지정되지 않았으면 파일명 사용)
+    let (spec, locale) = if let Some(name) = name {
         name
     } else {
-        file_path
+        let stem = file_path
             .file_stem()
             .and_then(|s| s.to_str())
-            .ok_or_else(|| anyhow::anyhow!("파일명을 확인할 수 없습니다"))?
-            .to_string()
+            .ok_or_else(|| anyhow::anyhow!(t!("common.file_stem_unknown")))?;
+        name::parse_localized(stem)?
     };
-
     // 템플릿 설치
-    template::install_template(file_path, &template_name, &config)?;
+    let warnings = with_writable_config(out, |config| {
+        template::install_localized_template(file_path, &spec, locale.as_deref(), config)
+    })?;
+    let template_name = match &locale {
+        Some(locale) => format!("{}.{}", spec, locale),
+        None => spec.to_string(),
+    };
 
-    println!(
-        "{} 템플릿 '{}'이(가) 설치되었습니다.",
+    if out.is_json() {
+        let warnings: Vec<String> = warnings.iter().map(|issue| issue.to_string()).collect();
+        return out.json(&json!({
+            "template": template_name,
+            "warnings": warnings,
+        }));
+    }
+
+    out.info(format!(
+        "{} {}",
         "✓".green(),
-        template_name.cyan()
-    );
+        t!("common.template_installed", template_name.cyan())
+    ));
+    for issue in warnings {
+        out.warn(issue);
+    }
+
+    Ok(())
+}
+
+/// template install --builtin, --all 명령 처리 (이름이 없으면 모든 내장 템플릿)
+fn handle_builtin_install(name: Option<&str>, out: Output) -> Result<()> {
+    let names = match name {
+        Some(name) => vec![name],
+        None => embedded::builtin_names(),
+    };
+
+    let results = with_writable_config(out, |config| {
+        let mut results = Vec::new();
+        for name in names {
+            results.extend(template::install_builtin_template(name, config)?);
+        }
+        Ok(results)
+    })?;
+
+    if out.is_json() {
+        let (installed, skipped): (Vec<_>, Vec<_>) = results.iter().partition(|r| r.installed);
+        let names = |list: Vec<&template::BuiltinInstall>| {
+            list.into_iter().map(|r| r.name.clone()).collect::<Vec<_>>()
+        };
+        return out.json(&json!({
+            "installed": names(installed),
+            "skipped": names(skipped),
+        }));
+    }
+
+    for result in &results {
+        if result.installed {
+            out.info(format!(
+                "{} {}",
+                "✓".green(),
+                t!("template.builtin_installed", result.name.cyan())
+            ));
+        } else {
+            out.info(format!(
+                "{} {}",
+                "-".dimmed(),
+                t!("template.builtin_skipped", result.name).dimmed()
+            ));
+        }
+    }
 
     Ok(())
 }
 
 /// template remove 명령 처리
-fn handle_template_remove(template_name: &str) -> Result<()> {
-    let config = config::load_config()?;
+fn handle_template_remove(template_name: &TemplateSpec, out: Output) -> Result<()> {
+    let (config, is_new_config) = writable_config()?;
 
     // 삭제 확인
-    let confirm = Confirm::new()
-        .with_prompt(format!(
-            "템플릿 '{}'을(를) 삭제하시겠습니까?",
-            template_name
-        ))
-        .default(false)
-        .interact()?;
+    let confirm = out.confirm(&t!("common.confirm_remove", template_name), false)?;
 
     if !confirm {
-        println!("{}", "작업이 취소되었습니다.".yellow());
-        return Ok(());
+        out.info(t!("common.cancelled").yellow());
+        return Err(Error::Cancelled.into());
     }
 
     // 템플릿 삭제
     template::remove_template(template_name, &config)?;
+    if is_new_config {
+        save_new_config(&config, out)?;
+    }
 
-    println!(
-        "{} 템플릿 '{}'이(가) 삭제되었습니다.",
+    if out.is_json() {
+        return out.json(&json!({ "removed": template_name.to_string() }));
+    }
+
+    out.info(format!(
+        "{} {}",
         "✓".green(),
-        template_name.cyan()
-    );
+        t!("common.template_removed", template_name.to_string().cyan())
+    ));
+
+    Ok(())
+}
+
+/// template new 명령 처리
+fn handle_template_new(
+    template_name: &TemplateSpec,
+    from: Option<&TemplateSpec>,
+    out: Output,
+) -> Result<()> {
+    let template = with_writable_config(out, |config| {
+        template::create_template(template_name, from, config)
+    })?;
+
+    if out.is_json() {
+        return out.json(&json!({ "template": template.id(), "path": template.path }));
+    }
+
+    out.info(format!(
+        "{} {}",
+        "✓".green(),
+        t!("template.created", template.id().cyan(), template.path.display())
+    ));
+    out.info(t!("template.edit_hint", template.id()));
 
     Ok(())
 }
 
+/// template edit 명령 처리
+fn handle_template_edit(template_name: &TemplateSpec, out: Output) -> Result<()> {
+    let (config, is_new_config) = writable_config()?;
+    let template = template::resolve_template(template_name, &config)?;
+    let original = template::get_template_content(&template.spec(), &config)?;
+
+    // 임시 파일에서 편집한 후 검증을 통과하면 저장
+    // (임의의 이름으로 새로 만들어 미리 만들어 둔 파일이나 심볼릭 링크를 따라가지 않음)
+    let mut edit_file = tempfile::Builder::new()
+        .prefix(&format!("initai-{}-", template.id().replace('@', "-")))
+        .suffix(".md")
+        .tempfile()?;
+    edit_file.write_all(original.as_bytes())?;
+    edit_file.flush()?;
+
+    let Some(content) = edit_until_valid(edit_file.path(), out)? else {
+        out.info(t!("template.edit_cancelled").yellow());
+        return Err(Error::Cancelled.into());
+    };
+
+    let changed = content != original;
+    if changed {
+        template::save_template_content(&template, &content, &config)?;
+        if is_new_config {
+            save_new_config(&config, out)?;
+        }
+    }
+
+    if out.is_json() {
+        return out.json(&json!({ "template": template.id(), "changed": changed }));
+    }
+    if !changed {
+        out.info(t!("template.no_changes").yellow());
+        return Ok(());
+    }
+
+    out.info(format!(
+        "{} {}",
+        "✓".green(),
+        t!("template.saved", template.id().cyan())
+    ));
+
+    Ok(())
+}
+
+/// 편집기를 열고 검증에 실패하면 다시 편집할지 확인 (취소 시 None)
+///
+/// 질문할 수 없으면 (JSON 형식 등) 다시 묻지 않고 검증 오류로 중단
+fn edit_until_valid(path: &std::path::Path, out: Output) -> Result<Option<String>> {
+    loop {
+        open_editor(path)?;
+        let content = std::fs::read_to_string(path)?;
+
+        match render::validate_content(&content) {
+            Ok(_) => return Ok(Some(content)),
+            Err(e) if !out.can_prompt() => return Err(e.into()),
+            Err(e) => {
+                eprintln!("{} {}", "✗".red(), format!("{:#}", e).red());
+
+                let retry = Confirm::new()
+                    .with_prompt(t!("template.invalid_retry"))
+                    .default(true)
+                    .interact()?;
+
+                if !retry {
+                    return Ok(None);
+                }
+            }
+        }
+    }
+}
+
+/// $VISUAL 또는 $EDITOR 편집기로 파일 열기
+fn open_editor(path: &std::path::Path) -> Result<()> {
+    let default_editor = if cfg!(windows) { "notepad" } else { "vi" };
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| default_editor.to_string());
+
+    let mut parts = editor.split_whitespace();
+    let program = parts
+        .next()
+        .ok_or_else(|| anyhow::anyhow!(t!("editor.not_set")))?;
+
+    let status = Command::new(program)
+        .args(parts)
+        .arg(path)
+        .status()
+        .map_err(|e| anyhow::anyhow!(t!("editor.spawn_failed", program, e)))?;
+
+    if !status.success() {
+        anyhow::bail!(t!("editor.failed", status));
+    }
+
+    Ok(())
+}
+
+/// template rename 명령 처리
+fn handle_template_rename(
+    old_name: &TemplateName,
+    new_name: &TemplateName,
+    out: Output,
+) -> Result<()> {
+    let updated = with_writable_config(out, |config| {
+        template::rename_template(old_name, new_name, config)
+    })?;
+
+    if out.is_json() {
+        let updated: Vec<String> = updated.iter().map(|template| template.id()).collect();
+        return out.json(&json!({
+            "from": old_name.as_str(),
+            "to": new_name.as_str(),
+            "updated": updated,
+        }));
+    }
+
+    out.info(format!(
+        "{} {}",
+        "✓".green(),
+        t!("template.renamed", old_name.as_str().cyan(), new_name.as_str().cyan())
+    ));
+    for template in updated {
+        out.info(format!("  - {}", t!("template.reference_updated", template.id())));
+    }
+
+    Ok(())
+}
+
+/// template copy 명령 처리
+fn handle_template_copy(
+    source: &TemplateName,
+    destination: &TemplateName,
+    out: Output,
+) -> Result<()> {
+    with_writable_config(out, |config| template::copy_template(source, destination, config))?;
+
+    if out.is_json() {
+        return out.json(&json!({ "from": source.as_str(), "to": destination.as_str() }));
+    }
+
+    out.info(format!(
+        "{} {}",
+        "✓".green(),
+        t!("template.copied", source.as_str().cyan(), destination.as_str().cyan())
+    ));
+
+    Ok(())
+}
+
+/// template show 명령 처리
+fn handle_template_show(template_name: &TemplateSpec, out: Output) -> Result<()> {
+    let config = config::load_or_builtin()?;
+    let template = template::resolve_template(template_name, &config)?;
+    let content = template::get_template_content(&template.spec(), &config)?;
+    let (metadata, _) = render::split_front_matter(&content)?;
+
+    if out.is_json() {
+        return out.json(&json!({
+            "id": template.id(),
+            "name": template.name.as_str(),
+            "version": template.version,
+            "path": template::installed_path(&template, &config)?,
+            "metadata": metadata,
+            "content": content,
+        }));
+    }
+
+    if out.quiet {
+        return print_paged(&content);
+    }
+
+    println!("{} {}", t!("show.name").cyan(), template.name);
+    if let Some(version) = &template.version {
+        println!("{} {}", t!("show.version").cyan(), version);
+    }
+    println!("{} {}", t!("show.path").cyan(), template.path.display());
+    if let Some(description) = &metadata.description {
+        println!("{} {}", t!("show.description").cyan(), description);
+    }
+    if !metadata.tags.is_empty() {
+        println!("{} {}", t!("show.tags").cyan(), metadata.tags.join(", "));
+    }
+    if let Some(parent) = &metadata.extends {
+        println!("{} {}", t!("show.parent").cyan(), parent);
+    }
+    if !metadata.variables.is_empty() {
+        println!("{}", t!("show.variables").cyan());
+        for (key, value) in &metadata.variables {
+            println!("  {} = {:?}", key, value);
+        }
+    }
+    println!();
+
+    print_paged(&content)
+}
+
+/// template preview 명령 처리
+fn handle_template_preview(template_name: &TemplateSpec, vars: &[String], out: Output) -> Result<()> {
+    let config = config::load_or_builtin()?;
+    let variables = parse_variables(vars)?;
+    let rendered = render::render_template(template_name, &variables, &config)?;
+
+    if out.is_json() {
+        return out.json(&json!({
+            "template": template_name.to_string(),
+            "content": rendered,
+            "tokens": TokenEstimate::of(&rendered),
+        }));
+    }
+
+    print_paged(&rendered)
+}
+
+/// `--var key=value` 인수 목록 파싱
+fn parse_variables(vars: &[String]) -> Result<BTreeMap<String, String>> {
+    Ok(vars.iter().map(|var| render::parse_variable(var)).collect::<init_ai::Result<_>>()?)
+}
+
+/// 터미널이면 페이저로, 아니면 그대로 표준 출력에 출력
+fn print_paged(content: &str) -> Result<()> {
+    if std::io::stdout().is_terminal() {
+        let pager = std::env::var("PAGER").unwrap_or_else(|_| "less -FRX".to_string());
+        let mut parts = pager.split_whitespace();
+
+        if let Some(program) = parts.next() {
+            if let Ok(mut child) = Command::new(program)
+                .args(parts)
+                .stdin(Stdio::piped())
+                .spawn()
+            {
+                if let Some(mut stdin) = child.stdin.take() {
+                    // 페이저가 먼저 종료된 경우의 쓰기 오류는 무시
+                    let _ = stdin.write_all(content.as_bytes());
+                }
+                child.wait()?;
+                return Ok(());
+            }
+        }
+    }
+
+    print!("{}", content);
+    if !content.ends_with('\n') {
+        println!();
+    }
+
+    Ok(())
+}
+
+/// template lint 명령 처리
+fn handle_template_lint(
+    target: Option<&TemplateSpec>,
+    file: Option<&std::path::Path>,
+    out: Output,
+) -> Result<()> {
+    let config = config::load_or_builtin()?;
+
+    let reports = match (target, file) {
+        (_, Some(file)) => vec![lint::lint_file(file, &config)?],
+        (Some(target), None) => vec![lint::lint_template(target, &config)?],
+        (None, None) => template::list_templates(&config)?
+            .iter()
+            .map(|template| lint::lint_template(&template.spec(), &config))
+            .collect::<init_ai::Result<_>>()?,
+    };
+
+    if out.is_json() {
+        out.json(&json!({ "reports": reports }))?;
+    } else {
+        for report in &reports {
+            if report.issues.is_empty() {
+                out.info(format!("{} {}", "✓".green(), report.target.cyan()));
+                continue;
+            }
+
+            println!("{} {}", "•".yellow(), report.target.cyan());
+            for issue in &report.issues {
+                let line = match issue.severity {
+                    lint::Severity::Error => issue.to_string().red(),
+                    lint::Severity::Warning => issue.to_string().yellow(),
+                };
+                println!("  {}", line);
+            }
+        }
+    }
+
+    let failed = reports.iter().filter(|r| r.has_errors()).count();
+    if failed > 0 {
+        return Err(out.fail(Error::Validation(t!("lint.failed", failed))));
+    }
+
+    Ok(())
+}
+
+/// template prune 명령 처리
+fn handle_template_prune(
+    template_name: Option<&TemplateName>,
+    keep: usize,
+    out: Output,
+) -> Result<()> {
+    let (config, is_new_config) = writable_config()?;
+    let prunable = template::find_prunable_templates(template_name, keep, &config)?;
+
+    let pruned: Vec<String> = prunable.iter().map(|template| template.id()).collect();
+
+    if prunable.is_empty() {
+        if out.is_json() {
+            return out.json(&json!({ "pruned": pruned }));
+        }
+        out.info(t!("prune.nothing").yellow());
+        return Ok(());
+    }
+
+    if !out.is_json() {
+        println!("{}", t!("prune.header").cyan());
+        for id in &pruned {
+            println!("  - {}", id);
+        }
+    }
+
+    // 삭제 확인
+    let confirm = out.confirm(&t!("prune.confirm", prunable.len()), false)?;
+
+    if !confirm {
+        out.info(t!("common.cancelled").yellow());
+        return Err(Error::Cancelled.into());
+    }
+
+    template::prune_templates(&prunable, &config)?;
+    if is_new_config {
+        save_new_config(&config, out)?;
+    }
+
+    if out.is_json() {
+        return out.json(&json!({ "pruned": pruned }));
+    }
+
+    out.info(format!(
+        "{} {}",
+        "✓".green(),
+        t!("prune.done", prunable.len())
+    ));
+
+    Ok(())
+}
+
+/// template reset 명령 처리
+fn handle_template_reset(name: &str, out: Output) -> Result<()> {
+    let (config, is_new_config) = writable_config()?;
+    // 대소문자를 구분하지 않고 입력한 이름 대신 내장 템플릿 이름으로 표시
+    let name = embedded::find_builtin(name).map_or(name, |(name, _)| name);
+    let files = template::builtin_files(name, &config)?;
+    let changed: Vec<&template::BuiltinFile> = files.iter().filter(|f| f.needs_reset()).collect();
+
+    // 설치된 내용 대비 원본의 diff (파일이 없으면 None)
+    let diff = |file: &template::BuiltinFile| {
+        let file_name = format!("{}.md", file.name);
+        file.current
+            .as_deref()
+            .map(|current| unified_diff(&file_name, current, file.original))
+    };
+    let report = |cancelled: bool| {
+        let files: Vec<_> = changed
+            .iter()
+            .map(|file| {
+                json!({
+                    "name": file.name,
+                    "path": file.path,
+                    "missing": file.current.is_none(),
+                    "diff": diff(file),
+                })
+            })
+            .collect();
+        json!({ "cancelled": cancelled, "files": files })
+    };
+
+    if changed.is_empty() {
+        if out.is_json() {
+            return out.json(&report(false));
+        }
+        out.info(format!("{} {}", "✓".green(), t!("reset.clean", name.cyan())));
+        return Ok(());
+    }
+
+    if !out.is_json() {
+        for file in &changed {
+            match diff(file) {
+                Some(diff) => {
+                    println!("{} {}", "~".yellow(), t!("reset.modified", file.path.display()));
+                    print_diff(&diff);
+                }
+                None => println!("{} {}", "+".green(), t!("reset.missing", file.path.display())),
+            }
+        }
+    }
+
+    // 되돌리기 확인
+    let confirm = out.confirm(&t!("reset.confirm", changed.len()), false)?;
+
+    if !confirm {
+        if out.is_json() {
+            out.json(&report(true))?;
+        } else {
+            out.info(t!("common.cancelled").yellow());
+        }
+        return Err(out.fail(Error::Cancelled));
+    }
+
+    template::reset_builtin_files(&changed, &config)?;
+    if is_new_config {
+        save_new_config(&config, out)?;
+    }
+
+    if out.is_json() {
+        return out.json(&report(false));
+    }
+    out.info(format!("{} {}", "✓".green(), t!("reset.done", name.cyan())));
+
+    Ok(())
+}
+
+/// completions 명령 처리
+///
+/// 셸 스크립트는 완성할 때마다 `initai`를 다시 호출하므로 템플릿 이름은 항상 현재 목록으로 완성됨
+fn handle_completions(shell: CompletionShell) -> Result<()> {
+    let shells = Shells::builtins();
+    let completer = shells
+        .completer(shell.name())
+        .expect("지원하는 셸은 clap_complete에 내장되어 있음");
+
+    let mut stdout = std::io::stdout().lock();
+    completer.write_registration("COMPLETE", "initai", "initai", "initai", &mut stdout)?;
+
+    Ok(())
+}
+
+/// man 명령 처리
+fn handle_man(out_dir: Option<std::path::PathBuf>, markdown: bool, out: Output) -> Result<()> {
+    let Some(dir) = out_dir else {
+        // 매뉴얼 페이지는 JSON 문서가 아니므로 JSON 형식에서는 파일로만 작성
+        if out.is_json() {
+            return Err(Usage(t!("man.json_requires_out_dir").to_string()).into());
+        }
+        let mut stdout = std::io::stdout().lock();
+        if markdown {
+            stdout.write_all(man::markdown().as_bytes())?;
+        } else {
+            man::render(&mut stdout)?;
+        }
+        return Ok(());
+    };
+
+    let files = if markdown {
+        vec![man::write_markdown(&dir)?]
+    } else {
+        man::write_pages(&dir)?
+    };
+
+    if out.is_json() {
+        out.json(&json!({ "files": files }))?;
+    } else {
+        for file in &files {
+            out.info(format!("  {}", file.display()));
+        }
+        out.info(format!(
+            "{} {}",
+            "✓".green(),
+            t!("man.written", files.len(), dir.display())
+        ));
+    }
+    Ok(())
+}
+
 /// config --set-template-path 명령 처리
-fn handle_config_set_path(path: std::path::PathBuf) -> Result<()> {
+fn handle_config_set_path(path: std::path::PathBuf, out: Output) -> Result<()> {
     config::set_template_path(path.clone())?;
 
-    println!(
-        "{} 템플릿 저장 경로가 설정되었습니다: {:?}",
+    out.info(format!(
+        "{} {}",
         "✓".green(),
-        path
-    );
+        t!("config.path_set", path.display())
+    ));
 
     // 기본 템플릿 설치
     let config = config::load_config()?;
     template::install_default_template(&config)?;
 
-    println!(
-        "{} 기본 템플릿 '{}'이(가) 설치되었습니다.",
+    if out.is_json() {
+        return out.json(&json!({ "template_path": config.template_path }));
+    }
+    out.info(format!(
+        "{} {}",
+        "✓".green(),
+        t!("config.default_installed", "Programming-Team".cyan())
+    ));
+
+    Ok(())
+}
+
+/// 템플릿을 작성하는 작업 실행 (설정 파일이 없으면 작업에 성공한 뒤에 만듦)
+///
+/// 설정 파일이 없으면 기본 템플릿 경로를 쓰는 설정으로 실행하므로, 원본을 찾지 못하는 등
+/// 실패한 명령은 설정 파일을 남기지 않음. 그 밖의 명령은 설정 없이 내장 템플릿 사용
+fn with_writable_config<T>(
+    out: Output,
+    write: impl FnOnce(&config::Config) -> init_ai::Result<T>,
+) -> Result<T> {
+    let (config, is_new) = writable_config()?;
+    let result = write(&config)?;
+    if is_new {
+        save_new_config(&config, out)?;
+    }
+
+    Ok(result)
+}
+
+/// 템플릿을 작성하는 명령의 설정과 설정 파일이 없어 새로 만든 설정인지 여부
+///
+/// 새 설정은 기본 템플릿 경로를 사용하며, 템플릿을 실제로 작성한 뒤에만 `save_new_config`로 저장
+/// (확인 프롬프트에서 취소하거나 바뀐 내용이 없으면 설정 파일을 만들지 않음)
+fn writable_config() -> Result<(config::Config, bool)> {
+    if config::config_exists()? {
+        return Ok((config::load_config()?, false));
+    }
+
+    Ok((config::Config::new(config::default_template_path()?), true))
+}
+
+/// 템플릿을 작성한 뒤 새로 만든 설정 저장
+fn save_new_config(config: &config::Config, out: Output) -> Result<()> {
+    config::save_config(config)?;
+
+    out.info(format!(
+        "{} {}",
         "✓".green(),
-        "Programming-Team".cyan()
+        t!("config.created", config.template_path.display())
+    ));
+
+    Ok(())
+}
+
+/// config list 명령 처리
+fn handle_config_list(out: Output) -> Result<()> {
+    let path = config::get_config_path()?;
+    let config = config::load_config()?;
+
+    if out.is_json() {
+        return out.json(&json!({
+            "config_path": path,
+            "template_path": config.template_path,
+            "default_template": config.default_template,
+            "max_tokens": config.max_tokens,
+            "fail_on_token_limit": config.fail_on_token_limit,
+            "language": config.language,
+            "locale": config.locale,
+        }));
+    }
+
+    let unset = || t!("common.unset").dimmed().to_string();
+    println!("{} {}", t!("config.file").cyan(), path.display());
+    println!("{} {}", "template_path:".cyan(), config.template_path.display());
+    println!("{} {}", "default_template:".cyan(), config.default_template);
+    println!(
+        "{} {}",
+        "max_tokens:".cyan(),
+        config.max_tokens.map_or_else(unset, |n| n.to_string())
+    );
+    println!(
+        "{} {}",
+        "fail_on_token_limit:".cyan(),
+        config.fail_on_token_limit
+    );
+    println!(
+        "{} {}",
+        "language:".cyan(),
+        config.language.map_or_else(unset, |lang| lang.to_string())
+    );
+    println!(
+        "{} {}",
+        "locale:".cyan(),
+        config.locale.clone().unwrap_or_else(unset)
     );
 
     Ok(())
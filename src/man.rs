@@ -0,0 +1,237 @@
+use anyhow::{Context, Result};
+use clap::{Arg, Command, CommandFactory};
+use std::fmt::Write as _;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::cli::Cli;
+use init_ai::i18n::{self, t};
+
+/// 마크다운 레퍼런스 파일 이름
+pub const MARKDOWN_FILE: &str = "commands.md";
+
+/// 현재 언어로 번역하고 하위 명령 이름까지 확정한 명령 정의
+pub fn command() -> Command {
+    let mut command = i18n::localize_command(Cli::command()).disable_help_subcommand(true);
+    command.build();
+    command
+}
+
+/// 매뉴얼 페이지 생성기 (날짜를 넣지 않아 같은 정의면 항상 같은 결과)
+fn page(command: Command) -> clap_mangen::Man {
+    clap_mangen::Man::new(command).source(format!("initai {}", env!("CARGO_PKG_VERSION")))
+}
+
+/// 최상위 명령의 매뉴얼 페이지 출력
+pub fn render(w: &mut dyn Write) -> Result<()> {
+    page(command()).render(w)?;
+    Ok(())
+}
+
+/// 모든 명령의 매뉴얼 페이지를 디렉토리에 작성 (`initai.1`, `initai-template-install.1` 등)
+pub fn write_pages(dir: &Path) -> Result<Vec<PathBuf>> {
+    fn write(command: Command, dir: &Path, written: &mut Vec<PathBuf>) -> Result<()> {
+        let man = page(command.clone());
+        let path = dir.join(man.get_filename());
+        let mut file = fs::File::create(&path)
+            .with_context(|| t!("man.write_failed", path.display()))?;
+        man.render(&mut file)
+            .with_context(|| t!("man.write_failed", path.display()))?;
+        written.push(path);
+
+        for sub in command.get_subcommands().filter(|s| !s.is_hide_set()) {
+            write(sub.clone(), dir, written)?;
+        }
+        Ok(())
+    }
+
+    fs::create_dir_all(dir).with_context(|| t!("man.write_failed", dir.display()))?;
+    let mut written = Vec::new();
+    write(command(), dir, &mut written)?;
+    Ok(written)
+}
+
+/// 마크다운 레퍼런스를 디렉토리에 작성
+pub fn write_markdown(dir: &Path) -> Result<PathBuf> {
+    fs::create_dir_all(dir).with_context(|| t!("man.write_failed", dir.display()))?;
+    let path = dir.join(MARKDOWN_FILE);
+    fs::write(&path, markdown()).with_context(|| t!("man.write_failed", path.display()))?;
+    Ok(path)
+}
+
+/// 모든 명령의 사용법, 인수, 옵션을 담은 마크다운 레퍼런스
+pub fn markdown() -> String {
+    let command = command();
+    let mut doc = format!(
+        "# {}\n\n<!-- {} -->\n",
+        t!("man.title"),
+        t!("man.generated")
+    );
+    write_command(&mut doc, &command, true);
+    doc
+}
+
+fn write_command(doc: &mut String, command: &Command, root: bool) {
+    let path = command.get_bin_name().unwrap_or_else(|| command.get_name());
+    let _ = write!(doc, "\n## `{}`\n\n", path);
+    if let Some(about) = command.get_about() {
+        let _ = writeln!(doc, "{}\n", about);
+    }
+
+    let aliases: Vec<String> = command.get_all_aliases().map(|a| format!("`{}`", a)).collect();
+    if !aliases.is_empty() {
+        let _ = writeln!(doc, "{}: {}\n", t!("man.aliases"), aliases.join(", "));
+    }
+
+    let usage = command.clone().render_usage().to_string();
+    let usage = usage.split_once(": ").map_or(usage.as_str(), |(_, u)| u);
+    let _ = writeln!(doc, "```text\n{}\n```", usage.trim());
+
+    // 전역 옵션과 도움말 옵션은 최상위 명령에서만 표시
+    let args: Vec<&Arg> = command
+        .get_arguments()
+        .filter(|a| !a.is_hide_set() && a.get_id() != "help")
+        .filter(|a| root || !a.is_global_set())
+        .collect();
+    let positionals: Vec<&Arg> = args.iter().copied().filter(|a| a.is_positional()).collect();
+    let options: Vec<&Arg> = args.iter().copied().filter(|a| !a.is_positional()).collect();
+    write_table(doc, t!("man.arguments"), &positionals);
+    write_table(doc, t!("man.options"), &options);
+
+    let subcommands: Vec<&Command> = command.get_subcommands().filter(|s| !s.is_hide_set()).collect();
+    if !subcommands.is_empty() {
+        let _ = write!(
+            doc,
+            "\n**{}**\n\n| {} | {} |\n| --- | --- |\n",
+            t!("man.commands"),
+            t!("man.commands"),
+            t!("man.description")
+        );
+        for sub in &subcommands {
+            let about = sub.get_about().map(|a| a.to_string()).unwrap_or_default();
+            let _ = writeln!(doc, "| `{}` | {} |", sub.get_name(), escape(&about));
+        }
+    }
+
+    for sub in subcommands {
+        write_command(doc, sub, false);
+    }
+}
+
+fn write_table(doc: &mut String, title: &str, args: &[&Arg]) {
+    if args.is_empty() {
+        return;
+    }
+    let _ = write!(
+        doc,
+        "\n**{}**\n\n| {} | {} |\n| --- | --- |\n",
+        title,
+        title,
+        t!("man.description")
+    );
+    for arg in args {
+        let _ = writeln!(doc, "| `{}` | {} |", signature(arg), escape(&description(arg)));
+    }
+}
+
+/// 인수 표기 (`<FILE_PATH>`, `[BACKUP]`, `-n, --name <NAME>`, `--var <KEY=VALUE>...`)
+fn signature(arg: &Arg) -> String {
+    let takes_value = arg.get_action().takes_values();
+    let value = arg
+        .get_value_names()
+        .and_then(|names| names.first())
+        .map(|name| name.to_string())
+        .unwrap_or_else(|| arg.get_id().as_str().to_uppercase());
+    let repeated = if matches!(arg.get_action(), clap::ArgAction::Append) {
+        "..."
+    } else {
+        ""
+    };
+
+    if arg.is_positional() {
+        return if arg.is_required_set() {
+            format!("<{}>{}", value, repeated)
+        } else {
+            format!("[{}]{}", value, repeated)
+        };
+    }
+
+    let mut names = Vec::new();
+    if let Some(short) = arg.get_short() {
+        names.push(format!("-{}", short));
+    }
+    if let Some(long) = arg.get_long() {
+        names.push(format!("--{}", long));
+    }
+    let mut signature = names.join(", ");
+    if takes_value {
+        let _ = write!(signature, " <{}>{}", value, repeated);
+    }
+    signature
+}
+
+/// 도움말에 기본값과 가능한 값 덧붙이기
+fn description(arg: &Arg) -> String {
+    let mut description = arg.get_help().map(|h| h.to_string()).unwrap_or_default();
+    if !arg.get_action().takes_values() {
+        return description;
+    }
+
+    let defaults: Vec<String> = arg
+        .get_default_values()
+        .iter()
+        .map(|v| format!("`{}`", v.to_string_lossy()))
+        .collect();
+    if !defaults.is_empty() {
+        let _ = write!(description, " ({})", t!("man.default", defaults.join(", ")));
+    }
+
+    let values: Vec<String> = arg
+        .get_possible_values()
+        .iter()
+        .filter(|v| !v.is_hide_set())
+        .map(|v| format!("`{}`", v.get_name()))
+        .collect();
+    if !values.is_empty() {
+        let _ = write!(description, " ({})", t!("man.values", values.join(", ")));
+    }
+    description
+}
+
+/// 표 셀 안에서 줄바꿈과 `|` 처리
+fn escape(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use init_ai::i18n::Lang;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_write_pages() {
+        let temp_dir = TempDir::new().unwrap();
+        let written = write_pages(temp_dir.path()).unwrap();
+
+        for name in ["initai.1", "initai-init.1", "initai-template-install.1", "initai-config-list.1"] {
+            assert!(written.contains(&temp_dir.path().join(name)), "{}", name);
+        }
+        let page = fs::read_to_string(temp_dir.path().join("initai-template-install.1")).unwrap();
+        assert!(page.contains("\\-\\-builtin"));
+    }
+
+    #[test]
+    fn test_markdown_is_up_to_date() {
+        i18n::set_language(Lang::Ko);
+        let generated = markdown();
+        assert!(generated.contains("## `initai template install`"));
+        assert!(generated.contains("`-n, --name <NAME>`"));
+        assert_eq!(
+            generated,
+            include_str!("../docs/commands.md"),
+            "docs/commands.md가 명령줄 정의와 다릅니다. `cargo run -- man --markdown --lang ko --out-dir docs`로 다시 생성하세요"
+        );
+    }
+}
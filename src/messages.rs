@@ -0,0 +1,1092 @@
+/// 메시지 카탈로그 (키, 영어, 한국어)
+///
+/// `{}`는 인수를 차례로, `{0}`, `{1}` 등은 위치를 지정해서 대입
+pub const MESSAGES: &[(&str, &str, &str)] = &[
+    // 명령줄 도움말
+    (
+        "cli.about",
+        "CLI tool that adds AI team collaboration rule files to your project",
+        "AI 팀 협업 규칙 파일을 프로젝트에 추가하는 CLI 도구",
+    ),
+    (
+        "cli.format",
+        "Output format (json: for scripts and editor integrations)",
+        "출력 형식 (json: 스크립트, 편집기 연동용)",
+    ),
+    ("cli.format.text", "Human-readable output", "사람이 읽기 쉬운 형식"),
+    ("cli.format.json", "JSON output for CI and scripts", "CI 등에서 사용할 JSON 형식"),
+    (
+        "cli.quiet",
+        "Print only results and errors, without informational messages",
+        "안내 메시지를 생략하고 결과와 오류만 출력",
+    ),
+    (
+        "cli.yes",
+        "Answer yes to every confirmation (needed to overwrite or delete outside a terminal)",
+        "확인 질문에 모두 예로 답함 (터미널이 아닌 환경에서 덮어쓰기, 삭제 등을 진행할 때 필요)",
+    ),
+    (
+        "cli.verbose",
+        "Show the full chain of causes when an error occurs",
+        "오류 발생 시 원인 체인까지 모두 표시",
+    ),
+    (
+        "cli.lang",
+        "Message language (defaults to the `language` config key, then LANG)",
+        "메시지 언어 (생략 시 설정 파일의 language, LANG 환경 변수 순)",
+    ),
+    ("cli.list.about", "List templates", "템플릿 목록 보기"),
+    ("cli.list.all_versions", "Show every installed version", "설치된 모든 버전 표시"),
+    (
+        "cli.list.long",
+        "Also show descriptions and estimated token counts",
+        "설명과 추정 토큰 수 함께 표시",
+    ),
+    ("cli.init.about", "Initialise the project", "프로젝트 초기화"),
+    (
+        "cli.init.template",
+        "Template to use (`name@version` for a specific version, `name@` for the unversioned file, latest if omitted)",
+        "사용할 템플릿 이름 (특정 버전은 `이름@버전`, 버전 없는 파일은 `이름@`, 생략 시 최신 버전)",
+    ),
+    (
+        "cli.init.template_flag",
+        "Template name (as the --template flag)",
+        "템플릿 이름 (--template 플래그 사용)",
+    ),
+    (
+        "cli.init.vars",
+        "Set a template variable (repeatable)",
+        "템플릿 변수 지정 (여러 번 사용 가능)",
+    ),
+    (
+        "cli.init.locale",
+        "Locale of the translation to use (e.g. en, ko, en-US; falls back to the base file)",
+        "사용할 번역본 로케일 (예: en, ko, en-US. 번역본이 없으면 기본 파일)",
+    ),
+    (
+        "cli.status.about",
+        "Show the state of files initai manages in this project",
+        "현재 프로젝트에서 initai가 관리하는 파일 상태 보기",
+    ),
+    (
+        "cli.check.about",
+        "Check that rule files match what their templates generate (for CI, writes nothing)",
+        "규칙 파일이 템플릿으로 생성한 결과와 같은지 확인 (CI용, 파일을 쓰지 않음)",
+    ),
+    (
+        "cli.doctor.about",
+        "Diagnose configuration, templates and project state",
+        "설정, 템플릿, 프로젝트 상태 진단",
+    ),
+    (
+        "cli.restore.about",
+        "Restore the rules file from a backup",
+        "백업된 이전 버전으로 규칙 파일 복원",
+    ),
+    (
+        "cli.restore.backup",
+        "Backup to restore (the latest backup if omitted)",
+        "복원할 백업 (생략 시 가장 최근 백업)",
+    ),
+    ("cli.restore.list", "List backups", "백업 목록 보기"),
+    ("cli.template.about", "Manage templates", "템플릿 관리"),
+    ("cli.template.list.about", "List templates", "템플릿 목록 보기"),
+    (
+        "cli.template.list.all_versions",
+        "Show every installed version",
+        "설치된 모든 버전 표시",
+    ),
+    (
+        "cli.template.list.long",
+        "Also show descriptions and estimated token counts",
+        "설명과 추정 토큰 수 함께 표시",
+    ),
+    ("cli.template.install.about", "Install a template", "템플릿 설치"),
+    (
+        "cli.template.install.file_path",
+        "Path of the template file to install",
+        "설치할 템플릿 파일 경로",
+    ),
+    (
+        "cli.template.install.name",
+        "Template name (`name@version` to set a version, `name.<locale>` for a translation, the file name if omitted)",
+        "템플릿 이름 (`이름@버전`으로 버전, `이름.<로케일>`로 번역본 지정, 지정하지 않으면 파일명 사용)",
+    ),
+    (
+        "cli.template.install.builtin",
+        "Install (or restore a deleted) built-in template",
+        "내장 템플릿 설치 (삭제한 내장 템플릿 복원)",
+    ),
+    (
+        "cli.template.install.all",
+        "Install every built-in template",
+        "모든 내장 템플릿 설치",
+    ),
+    ("cli.template.remove.about", "Remove a template", "템플릿 삭제"),
+    (
+        "cli.template.remove.template_name",
+        "Template to remove (every version if no version is given, only the unversioned file for `name@`)",
+        "삭제할 템플릿 이름 (버전을 지정하지 않으면 모든 버전, `이름@`이면 버전 없는 파일만 삭제)",
+    ),
+    ("cli.template.new.about", "Create a new template", "새 템플릿 생성"),
+    (
+        "cli.template.new.template_name",
+        "Name of the template to create",
+        "생성할 템플릿 이름",
+    ),
+    (
+        "cli.template.new.from",
+        "Existing template to copy the content from",
+        "내용을 복사할 기존 템플릿 이름",
+    ),
+    (
+        "cli.template.edit.about",
+        "Edit a template in your editor ($EDITOR)",
+        "템플릿을 편집기($EDITOR)로 편집",
+    ),
+    ("cli.template.edit.template_name", "Template to edit", "편집할 템플릿 이름"),
+    (
+        "cli.template.rename.about",
+        "Rename a template (moves every version and updates templates that reference it)",
+        "템플릿 이름 변경 (모든 버전 이동, 참조하는 템플릿 갱신)",
+    ),
+    ("cli.template.rename.old_name", "Current template name", "기존 템플릿 이름"),
+    ("cli.template.rename.new_name", "New template name", "새 템플릿 이름"),
+    (
+        "cli.template.copy.about",
+        "Copy a template (every version)",
+        "템플릿 복사 (모든 버전 복사)",
+    ),
+    ("cli.template.copy.source", "Template to copy", "복사할 템플릿 이름"),
+    ("cli.template.copy.destination", "New template name", "새 템플릿 이름"),
+    (
+        "cli.template.show.about",
+        "Show a template's source and metadata",
+        "템플릿 원본 내용과 메타데이터 보기",
+    ),
+    ("cli.template.show.template_name", "Template to show", "조회할 템플릿 이름"),
+    (
+        "cli.template.preview.about",
+        "Preview a rendered template (includes, variables and conditionals)",
+        "템플릿 렌더링 결과 미리보기 (include, 변수, 조건문 처리)",
+    ),
+    (
+        "cli.template.preview.template_name",
+        "Template to preview",
+        "미리볼 템플릿 이름",
+    ),
+    (
+        "cli.template.preview.vars",
+        "Set a template variable (repeatable)",
+        "템플릿 변수 지정 (여러 번 사용 가능)",
+    ),
+    (
+        "cli.template.lint.about",
+        "Lint templates (front matter, variables, includes, conditionals, headings, size)",
+        "템플릿 검사 (프론트 매터, 변수, include, 조건문, 제목 구조, 크기)",
+    ),
+    (
+        "cli.template.lint.target",
+        "Template name to lint (every installed template if omitted)",
+        "검사할 템플릿 이름 (생략 시 설치된 모든 템플릿)",
+    ),
+    (
+        "cli.template.lint.file",
+        "Lint a template file that is not installed",
+        "설치하지 않은 템플릿 파일 검사",
+    ),
+    (
+        "cli.template.reset.about",
+        "Restore a built-in template to its original content (shows a diff of local changes)",
+        "내장 템플릿을 원래 내용으로 되돌리기 (수정 내용 diff 표시)",
+    ),
+    (
+        "cli.template.reset.template_name",
+        "Built-in template to restore",
+        "되돌릴 내장 템플릿 이름",
+    ),
+    ("cli.template.prune.about", "Remove older template versions", "이전 버전 템플릿 정리"),
+    (
+        "cli.template.prune.template_name",
+        "Template to prune (every template if omitted)",
+        "정리할 템플릿 이름 (생략 시 모든 템플릿)",
+    ),
+    (
+        "cli.template.prune.keep",
+        "Number of newest versions to keep (at least 1)",
+        "남겨둘 최신 버전 수 (1 이상)",
+    ),
+    (
+        "cli.completions.about",
+        "Print a shell completion script (template names complete from the installed list)",
+        "셸 자동 완성 스크립트 출력 (템플릿 이름도 설치된 목록에서 완성)",
+    ),
+    ("cli.completions.shell", "Target shell", "대상 셸"),
+    (
+        "cli.man.about",
+        "Generate man pages and a Markdown command reference from the CLI definition",
+        "명령줄 정의로 매뉴얼 페이지와 마크다운 명령어 레퍼런스 생성",
+    ),
+    (
+        "cli.man.out_dir",
+        "Directory to write files to (prints the top-level page to stdout if omitted)",
+        "파일을 작성할 디렉토리 (생략 시 최상위 페이지를 표준 출력에 표시)",
+    ),
+    (
+        "cli.man.markdown",
+        "Generate a Markdown command reference instead of man pages",
+        "매뉴얼 페이지 대신 마크다운 명령어 레퍼런스 생성",
+    ),
+    ("cli.config.about", "Manage configuration", "설정 관리"),
+    (
+        "cli.config.set_template_path",
+        "Set the template storage path",
+        "템플릿 저장 경로 설정",
+    ),
+    ("cli.config.list.about", "Show the current configuration", "현재 설정 보기"),
+    // 공통
+    ("common.cancelled", "Operation cancelled.", "작업이 취소되었습니다."),
+    ("common.no_templates", "No templates available.", "사용 가능한 템플릿이 없습니다."),
+    (
+        "common.install_hint",
+        "Add templates with 'initai template install'.",
+        "'initai template install' 명령으로 템플릿을 추가하세요.",
+    ),
+    ("common.available_templates", "Available templates:", "사용 가능한 템플릿:"),
+    ("common.back", "Back", "돌아가기"),
+    (
+        "common.file_created",
+        "{} has been created. (template: {})",
+        "{} 파일이 생성되었습니다. (템플릿: {})",
+    ),
+    ("common.estimated_tokens", "Estimated tokens:", "추정 토큰:"),
+    (
+        "common.confirm_overwrite",
+        "{} already exists. Overwrite?",
+        "{} 파일이 이미 존재합니다. 덮어쓰시겠습니까?",
+    ),
+    (
+        "common.confirm_required",
+        "Confirmation required: {} Run in a terminal or pass --yes",
+        "확인이 필요합니다: {} 터미널에서 실행하거나 --yes를 지정하세요",
+    ),
+    (
+        "common.confirm_json",
+        "Confirmation required: {} JSON output never prompts, pass --yes",
+        "확인이 필요합니다: {} JSON 형식에서는 묻지 않으므로 --yes를 지정하세요",
+    ),
+    ("common.file_not_found", "File not found: {}", "파일을 찾을 수 없습니다: {}"),
+    (
+        "common.file_stem_unknown",
+        "Could not determine the file name",
+        "파일명을 확인할 수 없습니다",
+    ),
+    (
+        "common.template_installed",
+        "Template '{}' has been installed.",
+        "템플릿 '{}'이(가) 설치되었습니다.",
+    ),
+    (
+        "common.confirm_remove",
+        "Remove template '{}'?",
+        "템플릿 '{}'을(를) 삭제하시겠습니까?",
+    ),
+    (
+        "common.template_removed",
+        "Template '{}' has been removed.",
+        "템플릿 '{}'이(가) 삭제되었습니다.",
+    ),
+    ("common.unset", "(not set)", "(설정되지 않음)"),
+    // list
+    ("list.limit", "[limit {}]", "[한도 {}]"),
+    ("list.limit_exceeded", "[exceeds limit {}]", "[한도 {} 초과]"),
+    ("list.modified_builtin", "(modified)", "(수정됨)"),
+    (
+        "list.builtin_only_hint",
+        "No config file yet, so the built-in templates are shown. Installing a template creates one.",
+        "설정 파일이 없어 내장 템플릿을 표시합니다. 템플릿을 설치하면 설정 파일이 만들어집니다.",
+    ),
+    (
+        "list.reset_hint",
+        "Restore a modified built-in template with 'initai template reset <name>'.",
+        "수정된 내장 템플릿은 'initai template reset <이름>'으로 되돌릴 수 있습니다.",
+    ),
+    (
+        "list.skipped_file",
+        "Skipped {}: {}",
+        "{}을(를) 건너뛰었습니다: {}",
+    ),
+    (
+        "store.non_utf8_name",
+        "File name is not valid UTF-8",
+        "파일명이 올바른 UTF-8이 아닙니다",
+    ),
+    (
+        "store.orphan_translation",
+        "Translation without a base template '{}'",
+        "기본 템플릿 '{}'이(가) 없는 번역본입니다",
+    ),
+    // status
+    (
+        "status.not_initialized",
+        "This directory has not been initialised by initai (no lockfile).",
+        "이 디렉토리는 initai로 초기화되지 않았습니다 (락파일 없음).",
+    ),
+    (
+        "status.init_hint",
+        "Run 'initai init <template>' to initialise it.",
+        "'initai init <템플릿>' 명령으로 초기화하세요.",
+    ),
+    ("status.no_files", "No files are managed by initai.", "initai가 관리하는 파일이 없습니다."),
+    ("status.template", "Template:", "템플릿:"),
+    ("status.locale", "Locale:", "언어:"),
+    ("status.generated_at", "Generated at:", "생성 시각:"),
+    ("status.variables", "Variables:", "변수:"),
+    ("status.file", "File:", "파일:"),
+    ("status.file_missing", "missing (deleted)", "없음 (삭제됨)"),
+    ("status.file_modified", "edited since it was generated", "생성 이후 직접 수정됨"),
+    ("status.file_unmodified", "not modified", "수정되지 않음"),
+    (
+        "status.file_restored",
+        "restored from a backup (differs from the generated content)",
+        "백업에서 복원됨 (생성한 내용과 다름)",
+    ),
+    ("status.source", "Source template:", "원본 템플릿:"),
+    ("status.template_unchanged", "unchanged", "변경 없음"),
+    (
+        "status.template_changed",
+        "the template changed since generation",
+        "생성 이후 템플릿이 변경됨",
+    ),
+    (
+        "status.include_changed",
+        "an include or parent template changed since generation",
+        "생성 이후 include 또는 상위 템플릿이 변경됨",
+    ),
+    ("status.template_missing", "not installed", "설치되어 있지 않음"),
+    ("status.template_invalid", "cannot be rendered ({})", "렌더링할 수 없음 ({})"),
+    ("status.newer_version", "New version:", "새 버전:"),
+    (
+        "status.update_hint",
+        "(update with 'initai init {}')",
+        "('initai init {}'으로 업데이트)",
+    ),
+    // check
+    ("check.clean", "All files match their templates.", "모든 파일이 템플릿과 일치합니다."),
+    ("check.missing", "{} is missing.", "{} 파일이 없습니다."),
+    (
+        "check.differs",
+        "{} differs from what its template generates:",
+        "{} 파일이 템플릿으로 생성한 결과와 다릅니다:",
+    ),
+    (
+        "check.failed",
+        "{} file(s) differ from their templates. Regenerate them with 'initai init' or update the template",
+        "{}개 파일이 템플릿과 다릅니다. 'initai init'으로 다시 생성하거나 템플릿을 수정하세요",
+    ),
+    // doctor
+    (
+        "doctor.failed",
+        "Found {} error(s) and {} warning(s)",
+        "오류 {}개, 경고 {}개가 발견되었습니다",
+    ),
+    ("doctor.warnings", "Found {} warning(s).", "경고 {}개가 발견되었습니다."),
+    ("doctor.ok", "No problems found.", "문제가 발견되지 않았습니다."),
+    // restore
+    ("restore.no_backups", "No backups found.", "백업이 없습니다."),
+    (
+        "restore.list_header",
+        "Backups of rules/TEAM_RULES.md (newest first):",
+        "rules/TEAM_RULES.md 백업 목록 (최신순):",
+    ),
+    (
+        "restore.confirm",
+        "Restore rules/TEAM_RULES.md from backup '{}'? (the current file will be backed up)",
+        "rules/TEAM_RULES.md 파일을 백업 '{}'(으)로 복원하시겠습니까? (현재 파일은 백업됩니다)",
+    ),
+    (
+        "restore.done",
+        "rules/TEAM_RULES.md has been restored. (backup: {})",
+        "rules/TEAM_RULES.md 파일이 복원되었습니다. (백업: {})",
+    ),
+    // template 명령
+    (
+        "template.created",
+        "Template '{}' has been created: {}",
+        "템플릿 '{}'이(가) 생성되었습니다: {}",
+    ),
+    (
+        "template.edit_hint",
+        "Edit its content with 'initai template edit {}'.",
+        "'initai template edit {}' 명령으로 내용을 편집하세요.",
+    ),
+    (
+        "template.edit_cancelled",
+        "Editing was cancelled; no changes were saved.",
+        "편집이 취소되어 변경 사항이 저장되지 않았습니다.",
+    ),
+    ("template.no_changes", "No changes.", "변경 사항이 없습니다."),
+    ("template.saved", "Template '{}' has been saved.", "템플릿 '{}'이(가) 저장되었습니다."),
+    (
+        "template.invalid_retry",
+        "The template is invalid. Edit it again?",
+        "템플릿이 올바르지 않습니다. 다시 편집하시겠습니까?",
+    ),
+    (
+        "template.renamed",
+        "Template '{}' has been renamed to '{}'.",
+        "템플릿 '{}'의 이름이 '{}'(으)로 변경되었습니다.",
+    ),
+    ("template.reference_updated", "Updated reference: {}", "참조 갱신: {}"),
+    (
+        "template.copied",
+        "Template '{}' has been copied to '{}'.",
+        "템플릿 '{}'이(가) '{}'(으)로 복사되었습니다.",
+    ),
+    ("show.name", "Name:", "이름:"),
+    ("show.version", "Version:", "버전:"),
+    ("show.path", "Path:", "경로:"),
+    ("show.description", "Description:", "설명:"),
+    ("show.tags", "Tags:", "태그:"),
+    ("show.parent", "Parent template:", "상위 템플릿:"),
+    ("show.variables", "Variables:", "변수:"),
+    (
+        "lint.failed",
+        "Found errors in {} template(s)",
+        "템플릿 {}개에서 오류가 발견되었습니다",
+    ),
+    (
+        "prune.nothing",
+        "There are no older template versions to prune.",
+        "정리할 이전 버전 템플릿이 없습니다.",
+    ),
+    (
+        "prune.header",
+        "The following templates will be removed:",
+        "다음 템플릿이 삭제됩니다:",
+    ),
+    (
+        "prune.confirm",
+        "Remove {} older template version(s)?",
+        "이전 버전 템플릿 {}개를 삭제하시겠습니까?",
+    ),
+    (
+        "prune.done",
+        "Removed {} older template version(s).",
+        "이전 버전 템플릿 {}개가 삭제되었습니다.",
+    ),
+    (
+        "reset.clean",
+        "Built-in template '{}' already matches the original.",
+        "내장 템플릿 '{}'은(는) 이미 원래 내용과 같습니다.",
+    ),
+    (
+        "reset.missing",
+        "{} is not installed and will be installed again",
+        "{} 파일이 없어 새로 설치됩니다",
+    ),
+    ("reset.modified", "{} has local changes:", "{} 파일이 수정되었습니다:"),
+    (
+        "reset.confirm",
+        "Restore {} file(s) to the built-in content? Local changes will be lost",
+        "{}개 파일을 내장 내용으로 되돌리시겠습니까? 수정 내용은 사라집니다",
+    ),
+    (
+        "reset.done",
+        "Built-in template '{}' has been restored.",
+        "내장 템플릿 '{}'을(를) 원래 내용으로 되돌렸습니다.",
+    ),
+    // 편집기
+    ("editor.not_set", "No editor is configured", "편집기가 설정되지 않았습니다"),
+    (
+        "editor.spawn_failed",
+        "Could not start the editor ({}): {}",
+        "편집기를 실행할 수 없습니다 ({}): {}",
+    ),
+    (
+        "editor.failed",
+        "The editor exited abnormally ({})",
+        "편집기가 비정상 종료되었습니다 ({})",
+    ),
+    // man
+    ("man.written", "Wrote {} file(s) to {}", "{1}에 파일 {0}개를 작성했습니다"),
+    ("man.write_failed", "Failed to write {}", "{} 작성 실패"),
+    (
+        "man.json_requires_out_dir",
+        "man prints roff or Markdown, not JSON. Pass --out-dir with --format json",
+        "man은 JSON이 아닌 roff나 마크다운을 출력합니다. --format json에서는 --out-dir을 지정하세요",
+    ),
+    ("man.title", "initai command reference", "initai 명령어 레퍼런스"),
+    (
+        "man.generated",
+        "This file is generated by `initai man --markdown`. Do not edit it by hand.",
+        "이 파일은 `initai man --markdown`으로 생성됩니다. 직접 수정하지 마세요.",
+    ),
+    ("man.aliases", "Aliases", "별칭"),
+    ("man.arguments", "Arguments", "인수"),
+    ("man.options", "Options", "옵션"),
+    ("man.commands", "Commands", "하위 명령"),
+    ("man.description", "Description", "설명"),
+    ("man.default", "default: {}", "기본값: {}"),
+    ("man.values", "values: {}", "값: {}"),
+    // config 명령
+    (
+        "config.usage",
+        "Usage: initai config --set-template-path <path> | initai config list",
+        "사용법: initai config --set-template-path <경로> | initai config list",
+    ),
+    ("config.file", "Config file:", "설정 파일:"),
+    (
+        "config.path_set",
+        "Template path has been set: {}",
+        "템플릿 저장 경로가 설정되었습니다: {}",
+    ),
+    (
+        "config.default_installed",
+        "Default template '{}' has been installed.",
+        "기본 템플릿 '{}'이(가) 설치되었습니다.",
+    ),
+    // 설정 파일
+    (
+        "config.dir_not_found",
+        "Could not find the config directory",
+        "설정 디렉토리를 찾을 수 없습니다",
+    ),
+    (
+        "config.dir_create_failed",
+        "Could not create the config directory",
+        "설정 디렉토리를 생성할 수 없습니다",
+    ),
+    (
+        "config.created",
+        "Created a config file with template path {}",
+        "템플릿 경로를 {}(으)로 설정 파일을 만들었습니다",
+    ),
+    (
+        "config.missing",
+        "The config file does not exist. Set a template path first.",
+        "설정 파일이 존재하지 않습니다. 템플릿 경로를 먼저 설정해주세요.",
+    ),
+    ("config.read_failed", "Could not read the config file", "설정 파일을 읽을 수 없습니다"),
+    ("config.invalid", "The config file is invalid", "설정 파일 형식이 올바르지 않습니다"),
+    (
+        "config.serialize_failed",
+        "Could not serialise the configuration",
+        "설정을 직렬화할 수 없습니다",
+    ),
+    ("config.save_failed", "Could not save the config file", "설정 파일을 저장할 수 없습니다"),
+    (
+        "config.create_dir_failed",
+        "Could not create directory: {}",
+        "디렉토리를 생성할 수 없습니다: {}",
+    ),
+    // 대화형 모드
+    ("interactive.title", "=== initai interactive mode ===", "=== initai 대화형 모드 ==="),
+    (
+        "interactive.not_terminal",
+        "Interactive mode needs a terminal. Pass a template name instead, e.g. 'initai init <TEMPLATE>'",
+        "대화형 모드는 터미널에서만 사용할 수 있습니다. 'initai init <템플릿>'처럼 템플릿 이름을 지정하세요",
+    ),
+    (
+        "interactive.json_unsupported",
+        "Interactive mode does not support --format json. Pass a command, e.g. 'initai --format json init <TEMPLATE>'",
+        "대화형 모드는 --format json을 지원하지 않습니다. 'initai --format json init <템플릿>'처럼 명령을 지정하세요",
+    ),
+    ("interactive.exit", "Goodbye.", "종료합니다."),
+    ("interactive.menu_prompt", "What would you like to do?", "원하는 작업을 선택하세요"),
+    ("interactive.menu_start", "Start a project", "프로젝트 시작하기"),
+    ("interactive.menu_manage", "Manage templates", "템플릿 관리"),
+    ("interactive.menu_exit", "Exit", "종료"),
+    ("interactive.manage_install", "Install a template", "템플릿 등록"),
+    ("interactive.manage_remove", "Remove a template", "템플릿 삭제"),
+    ("interactive.manage_list", "List templates", "템플릿 목록 보기"),
+    (
+        "interactive.file_path_prompt",
+        "Path of the template file (leave empty to cancel)",
+        "템플릿 파일 경로를 입력하세요 (취소: 빈 입력 후 Enter)",
+    ),
+    (
+        "interactive.name_prompt",
+        "Template name (defaults to the file name)",
+        "템플릿 이름을 입력하세요 (취소: 빈 입력, 기본값: 파일명)",
+    ),
+    (
+        "interactive.remove_prompt",
+        "Select a template to remove",
+        "삭제할 템플릿을 선택하세요",
+    ),
+    ("interactive.select_prompt", "Select a template", "템플릿을 선택하세요"),
+    (
+        "interactive.picker_help",
+        "Type to search · ↑↓ move · Tab select several · Enter confirm · Esc back",
+        "입력해서 검색 · ↑↓ 이동 · Tab 여러 개 선택 · Enter 확인 · Esc 뒤로",
+    ),
+    (
+        "interactive.item_unreadable",
+        "Could not read template '{}'; showing it without a description: {}",
+        "템플릿 '{}'을(를) 읽을 수 없어 설명 없이 표시합니다: {}",
+    ),
+    ("interactive.picker_no_match", "No matching templates", "일치하는 템플릿이 없습니다"),
+    ("interactive.picker_more", "… {} more", "… 외 {}개"),
+    (
+        "interactive.picker_not_terminal",
+        "The template picker needs a terminal",
+        "템플릿 선택 화면은 터미널에서만 사용할 수 있습니다",
+    ),
+    (
+        "interactive.compose_name_prompt",
+        "Name for the combined template",
+        "조합한 템플릿 이름",
+    ),
+    (
+        "interactive.composed",
+        "Created template {} combining {}",
+        "{1}을(를) 조합한 템플릿 {0}을(를) 만들었습니다",
+    ),
+    // 템플릿 저장소
+    (
+        "template.dir_read_failed",
+        "Could not read the template directory: {}",
+        "템플릿 디렉토리를 읽을 수 없습니다: {}",
+    ),
+    ("template.not_found", "Template '{}' not found", "템플릿 '{}'을(를) 찾을 수 없습니다"),
+    (
+        "template.markdown_only",
+        "Only Markdown files (.md) can be installed",
+        "마크다운 파일(.md)만 설치할 수 있습니다",
+    ),
+    (
+        "template.lint_failed",
+        "The template failed lint checks and was not installed:\n{}",
+        "템플릿 검사에 실패하여 설치하지 않았습니다:\n{}",
+    ),
+    (
+        "template.exists_use_version",
+        "Template '{0}' already exists. Install a new version with a name like '{0}@<version>'",
+        "템플릿 '{0}'이(가) 이미 존재합니다. 새 버전은 '{0}@<버전>' 형식의 이름으로 설치하세요",
+    ),
+    ("template.exists", "Template '{}' already exists", "템플릿 '{}'이(가) 이미 존재합니다"),
+    ("template.composed_description", "Combination of {}", "{} 조합"),
+    (
+        "template.read_file_failed",
+        "Could not read template file: {}",
+        "템플릿 파일을 읽을 수 없습니다: {}",
+    ),
+    (
+        "template.symlink",
+        "Symbolic links are not supported",
+        "심볼릭 링크는 지원하지 않습니다",
+    ),
+    (
+        "template.regular_file_only",
+        "Only regular files can be installed: {}",
+        "일반 파일만 설치할 수 있습니다: {}",
+    ),
+    ("template.open_failed", "Could not open file: {}", "파일을 열 수 없습니다: {}"),
+    (
+        "template.changed_during_check",
+        "The file changed while it was being checked: {}",
+        "파일이 검사 도중 변경되었습니다: {}",
+    ),
+    ("template.too_large", "The file is larger than 10MB", "파일 크기가 10MB를 초과합니다"),
+    ("template.read_failed", "Could not read file: {}", "파일을 읽을 수 없습니다: {}"),
+    (
+        "template.utf8_only",
+        "Only UTF-8 text files can be installed",
+        "UTF-8 텍스트 파일만 설치할 수 있습니다",
+    ),
+    ("template.remove_failed", "Could not remove template: {}", "템플릿을 삭제할 수 없습니다: {}"),
+    (
+        "template.rename_failed",
+        "Could not rename template: {} -> {}",
+        "템플릿 이름을 변경할 수 없습니다: {} -> {}",
+    ),
+    (
+        "template.copy_failed",
+        "Could not copy template: {} -> {}",
+        "템플릿을 복사할 수 없습니다: {} -> {}",
+    ),
+    ("template.save_failed", "Could not save template: {}", "템플릿을 저장할 수 없습니다: {}"),
+    (
+        "template.default_install_failed",
+        "Could not install the default template: {}",
+        "기본 템플릿을 설치할 수 없습니다: {}",
+    ),
+    (
+        "template.builtin_not_found",
+        "No built-in template named '{}' (available: {})",
+        "내장 템플릿 '{}'을(를) 찾을 수 없습니다 (사용 가능: {})",
+    ),
+    (
+        "template.builtin_remove",
+        "'{}' is a built-in template and cannot be removed",
+        "'{}'은(는) 내장 템플릿이라 삭제할 수 없습니다",
+    ),
+    (
+        "template.builtin_rename",
+        "'{}' is a built-in template name and cannot be renamed (copy it with 'initai template copy' instead)",
+        "'{}'은(는) 내장 템플릿 이름이라 변경할 수 없습니다 ('initai template copy'로 복사하세요)",
+    ),
+    (
+        "template.builtin_installed",
+        "Built-in template '{}' has been installed.",
+        "내장 템플릿 '{}'이(가) 설치되었습니다.",
+    ),
+    (
+        "template.builtin_skipped",
+        "'{}' is already installed, skipped.",
+        "'{}'은(는) 이미 설치되어 있어 건너뜁니다.",
+    ),
+    // 템플릿 렌더링
+    (
+        "render.front_matter_invalid",
+        "Template front matter is malformed",
+        "템플릿 프론트 매터 형식이 올바르지 않습니다",
+    ),
+    (
+        "render.front_matter_unclosed",
+        "Template front matter is not closed (missing '{}')",
+        "템플릿 프론트 매터가 닫히지 않았습니다 ('{}' 누락)",
+    ),
+    ("render.unclosed_tag", "Unclosed tag: '{{'", "닫히지 않은 태그가 있습니다: '{{'"),
+    (
+        "render.else_without_if",
+        "'{{else}}' has no matching '{{#if}}'",
+        "'{{else}}'에 대응하는 '{{#if}}'가 없습니다",
+    ),
+    (
+        "render.endif_without_if",
+        "'{{/if}}' has no matching '{{#if}}'",
+        "'{{/if}}'에 대응하는 '{{#if}}'가 없습니다",
+    ),
+    ("render.if_unclosed", "'{}' is not closed", "'{}'가 닫히지 않았습니다"),
+    (
+        "render.invalid_reference",
+        "Invalid template reference: '{}'",
+        "올바르지 않은 템플릿 참조입니다: '{}'",
+    ),
+    (
+        "render.cycle",
+        "Template reference cycle detected: {} -> {}",
+        "템플릿 순환 참조가 감지되었습니다: {} -> {}",
+    ),
+    (
+        "render.too_deep",
+        "Template includes are nested deeper than {}",
+        "템플릿 include 깊이가 {}을(를) 초과합니다",
+    ),
+    (
+        "render.reference_failed",
+        "Could not render template '{}'",
+        "템플릿 '{}'을(를) 렌더링할 수 없습니다",
+    ),
+    (
+        "render.variable_format",
+        "Variables must use the 'key=value' format: '{}'",
+        "변수는 'key=value' 형식이어야 합니다: '{}'",
+    ),
+    ("render.variable_name", "Invalid variable name: '{}'", "올바르지 않은 변수 이름입니다: '{}'"),
+    // 템플릿 검사
+    (
+        "lint.too_large",
+        "Template is {}KB, larger than the recommended {}KB",
+        "템플릿 크기가 {}KB로 권장 크기({}KB)를 넘습니다",
+    ),
+    (
+        "lint.undefined_variable",
+        "Variable '{}' has no default and is written as-is unless passed with --var. Define it under [variables] in the front matter",
+        "변수 '{}'의 기본값이 없어 --var로 지정하지 않으면 태그 그대로 출력됩니다. 프론트 매터 [variables]에 정의하세요",
+    ),
+    (
+        "lint.unknown_tag",
+        "Unknown tag '{}' is written as-is. Escape it as '\\{{' to silence this warning",
+        "알 수 없는 태그 '{}'는 그대로 출력됩니다. 의도한 것이면 '\\{{'로 표시하세요",
+    ),
+    (
+        "lint.unused_variable",
+        "Variable '{}' is defined but never used",
+        "변수 '{}'이(가) 정의되었지만 사용되지 않습니다",
+    ),
+    (
+        "lint.unknown_key",
+        "Unknown front matter key: '{}' (available: {})",
+        "알 수 없는 프론트 매터 키입니다: '{}' (사용 가능: {})",
+    ),
+    (
+        "lint.duplicate_else",
+        "'{{else}}' is used more than once in a single '{{#if}}'",
+        "하나의 '{{#if}}'에 '{{else}}'가 두 번 이상 사용되었습니다",
+    ),
+    (
+        "lint.missing_reference",
+        "Referenced template '{}' is not installed",
+        "참조한 템플릿 '{}'이(가) 설치되어 있지 않습니다",
+    ),
+    ("lint.empty_heading", "Heading is empty", "제목이 비어 있습니다"),
+    (
+        "lint.heading_skip",
+        "Heading level jumps from h{} to h{}: '{}'",
+        "제목 레벨이 h{}에서 h{}(으)로 건너뜁니다: '{}'",
+    ),
+    (
+        "lint.duplicate_heading",
+        "Duplicate heading '{}' in the same section (first on line {})",
+        "같은 섹션에 '{}' 제목이 중복됩니다 ({}번째 줄)",
+    ),
+    // 프로젝트 초기화
+    (
+        "init.locale_fallback",
+        "Template '{}' has no '{}' translation; using the default file",
+        "템플릿 '{}'의 '{}' 번역본이 없어 기본 파일을 사용합니다",
+    ),
+    (
+        "init.token_limit",
+        "Estimated tokens for template '{}' ({}) exceed the limit ({})",
+        "템플릿 '{}'의 추정 토큰 수({})가 한도({})를 넘습니다",
+    ),
+    ("init.lockfile_reset", "{}. Writing a new lockfile", "{}. 락파일을 새로 작성합니다"),
+    (
+        "init.lockfile_template_invalid",
+        "Invalid template in lockfile: '{}'",
+        "락파일의 템플릿이 올바르지 않습니다: '{}'",
+    ),
+    (
+        "init.created_meanwhile",
+        "{} already exists. Please try again",
+        "{} 파일이 이미 존재합니다. 다시 시도하세요",
+    ),
+    ("init.write_failed", "Could not write {}", "{} 파일을 작성하지 못했습니다"),
+    ("init.nothing_changed", ". No files were changed", ". 변경된 파일은 없습니다"),
+    ("init.reverted", ". Reverted files already written: {}", ". 이미 작성한 파일을 되돌렸습니다: {}"),
+    ("init.revert_failed", ". Could not revert: {}", ". 되돌리지 못한 항목: {}"),
+    ("init.create_dir_failed", "Could not create directory: {}", "디렉토리를 생성할 수 없습니다: {}"),
+    ("init.read_existing_failed", "Could not read existing file: {}", "기존 파일을 읽을 수 없습니다: {}"),
+    ("init.backup_failed", "Could not back up existing file: {}", "기존 파일을 백업할 수 없습니다: {}"),
+    // 진단
+    ("doctor.check_config", "Config file", "설정 파일"),
+    (
+        "doctor.config_missing",
+        "No config file; using built-in templates only: {}",
+        "설정 파일이 없어 내장 템플릿만 사용합니다: {}",
+    ),
+    (
+        "doctor.config_missing_fix",
+        "To use your own templates, set a template path with 'initai config --set-template-path <PATH>'",
+        "직접 만든 템플릿을 쓰려면 'initai config --set-template-path <경로>'로 템플릿 경로를 설정하세요",
+    ),
+    (
+        "doctor.config_invalid_fix",
+        "Fix or delete the config file, then run 'initai config --set-template-path <PATH>' again",
+        "설정 파일을 수정하거나 삭제한 뒤 'initai config --set-template-path <경로>'를 다시 실행하세요",
+    ),
+    ("doctor.check_template_dir", "Template directory", "템플릿 디렉토리"),
+    ("doctor.dir_missing", "Directory does not exist: {}", "디렉토리가 존재하지 않습니다: {}"),
+    (
+        "doctor.dir_missing_fix",
+        "Create the directory and install the default templates with 'initai config --set-template-path {}'",
+        "'initai config --set-template-path {}'로 디렉토리를 만들고 기본 템플릿을 설치하세요",
+    ),
+    ("doctor.not_dir", "Not a directory: {}", "디렉토리가 아닙니다: {}"),
+    (
+        "doctor.not_dir_fix",
+        "Choose another path with 'initai config --set-template-path <PATH>'",
+        "'initai config --set-template-path <경로>'로 다른 경로를 지정하세요",
+    ),
+    ("doctor.dir_unreadable", "Cannot read directory ({}): {}", "디렉토리를 읽을 수 없습니다 ({}): {}"),
+    (
+        "doctor.dir_unreadable_fix",
+        "Check read permissions (e.g. chmod u+rx {})",
+        "읽기 권한을 확인하세요 (예: chmod u+rx {})",
+    ),
+    (
+        "doctor.dir_readonly",
+        "No write permission; templates cannot be installed or edited ({}): {}",
+        "쓰기 권한이 없어 템플릿을 설치하거나 편집할 수 없습니다 ({}): {}",
+    ),
+    (
+        "doctor.dir_readonly_fix",
+        "Check write permissions (e.g. chmod u+w {})",
+        "쓰기 권한을 확인하세요 (예: chmod u+w {})",
+    ),
+    (
+        "doctor.skipped_file",
+        "Not read as a template ({}): {}",
+        "템플릿으로 읽지 않은 파일입니다 ({}): {}",
+    ),
+    (
+        "doctor.skipped_file_fix",
+        "Rename the file to `name[@version][.locale].md` (a translation needs a base file of the same name and version) or move it out of the template directory",
+        "파일명을 `이름[@버전][.로케일].md` 형식으로 바꾸거나(번역본은 같은 이름과 버전의 기본 파일 필요) 템플릿 디렉토리 밖으로 옮기세요",
+    ),
+    ("doctor.check_templates", "Templates", "템플릿"),
+    ("doctor.check_template", "Template {}", "템플릿 {}"),
+    (
+        "doctor.templates_unreadable_fix",
+        "Check the template directory permissions",
+        "템플릿 디렉토리 권한을 확인하세요",
+    ),
+    ("doctor.no_templates", "No templates installed", "설치된 템플릿이 없습니다"),
+    (
+        "doctor.no_templates_fix",
+        "Add a template with 'initai template install <FILE>'",
+        "'initai template install <파일>'로 템플릿을 추가하세요",
+    ),
+    (
+        "doctor.template_unreadable_fix",
+        "Check the file permissions and encoding (UTF-8): {}",
+        "파일 권한과 인코딩(UTF-8)을 확인하세요: {}",
+    ),
+    (
+        "doctor.lint_fix",
+        "Inspect it with 'initai template lint {0}' and fix it with 'initai template edit {0}'",
+        "'initai template lint {0}'로 내용을 확인하고 'initai template edit {0}'로 수정하세요",
+    ),
+    ("doctor.lint_errors", "{} lint errors, {} warnings", "검사 오류 {}개, 경고 {}개"),
+    ("doctor.lint_warnings", "{} lint warnings", "검사 경고 {}개"),
+    ("doctor.healthy", "OK", "정상"),
+    ("doctor.check_lockfile", "Project lockfile", "프로젝트 락파일"),
+    (
+        "doctor.lockfile_invalid_fix",
+        "Delete {} and regenerate it with 'initai init <TEMPLATE>'",
+        "{}을(를) 삭제한 뒤 'initai init <템플릿>'으로 다시 생성하세요",
+    ),
+    (
+        "doctor.lockfile_missing",
+        "{} exists but there is no lockfile, so its origin cannot be checked",
+        "{} 파일이 있지만 락파일이 없어 생성 정보를 확인할 수 없습니다",
+    ),
+    (
+        "doctor.lockfile_missing_fix",
+        "Regenerating it with 'initai init <TEMPLATE>' records a lockfile",
+        "'initai init <템플릿>'으로 다시 생성하면 락파일이 기록됩니다",
+    ),
+    ("doctor.not_initialized", "Not a project initialized by initai", "initai로 초기화된 프로젝트가 아닙니다"),
+    ("doctor.managed_files", "Managing {} files", "{}개 파일 관리 중"),
+    ("doctor.reinit_fix", "Regenerate it with 'initai init {}'", "'initai init {}'으로 다시 생성하세요"),
+    ("doctor.file_missing", "File recorded in the lockfile is missing", "락파일에 기록된 파일이 없습니다"),
+    (
+        "doctor.file_missing_fix",
+        "{} (or 'initai restore' if a backup exists)",
+        "{} (백업이 있으면 'initai restore')",
+    ),
+    (
+        "doctor.template_missing",
+        "Template '{}' used to generate it is not installed",
+        "생성에 사용한 템플릿 '{}'이(가) 설치되어 있지 않습니다",
+    ),
+    (
+        "doctor.template_missing_fix",
+        "Install it with 'initai template install <FILE> --name <NAME@VERSION>'",
+        "'initai template install <파일> --name <이름@버전>'으로 템플릿을 설치하세요",
+    ),
+    (
+        "doctor.template_invalid",
+        "Cannot render template '{}': {}",
+        "템플릿 '{}'을(를) 렌더링할 수 없습니다: {}",
+    ),
+    (
+        "doctor.template_invalid_fix",
+        "Check it with 'initai template lint {}'",
+        "'initai template lint {}'로 확인하세요",
+    ),
+    (
+        "doctor.template_changed",
+        "Template '{}' has changed since generation",
+        "생성 이후 템플릿 '{}'이(가) 변경되었습니다",
+    ),
+    ("doctor.newer_version", "A newer version '{}' is installed", "새 버전 '{}'이(가) 설치되어 있습니다"),
+    ("doctor.newer_version_fix", "Update with 'initai init {}'", "'initai init {}'으로 업데이트하세요"),
+    ("doctor.file_modified", "File was edited by hand after generation", "생성 이후 파일이 직접 수정되었습니다"),
+    (
+        "doctor.file_modified_fix",
+        "Move the changes into the template, or regenerate to discard them",
+        "변경 사항을 템플릿에 반영하거나, 되돌리려면 다시 생성하세요",
+    ),
+    ("doctor.up_to_date", "Up to date (template: {})", "최신 상태 (템플릿: {})"),
+    // 백업
+    ("backup.invalid_path", "Invalid file path: {}", "올바르지 않은 파일 경로입니다: {}"),
+    ("backup.write_failed", "Could not write file: {}", "파일을 작성할 수 없습니다: {}"),
+    (
+        "backup.create_dir_failed",
+        "Could not create backup directory: {}",
+        "백업 디렉토리를 생성할 수 없습니다: {}",
+    ),
+    ("backup.copy_failed", "Could not create backup: {} -> {}", "백업을 생성할 수 없습니다: {} -> {}"),
+    (
+        "backup.read_dir_failed",
+        "Could not read backup directory: {}",
+        "백업 디렉토리를 읽을 수 없습니다: {}",
+    ),
+    ("backup.remove_failed", "Could not delete backup: {}", "백업을 삭제할 수 없습니다: {}"),
+    ("backup.not_found", "Backup '{}' not found", "백업 '{}'을(를) 찾을 수 없습니다"),
+    ("backup.none", "No backups to restore: {}", "복원할 백업이 없습니다: {}"),
+    ("backup.read_failed", "Could not read backup file: {}", "백업 파일을 읽을 수 없습니다: {}"),
+    // 락파일
+    ("lockfile.read_failed", "Could not read lockfile: {}", "락파일을 읽을 수 없습니다: {}"),
+    ("lockfile.invalid", "Lockfile is malformed: {}", "락파일 형식이 올바르지 않습니다: {}"),
+    (
+        "lockfile.unsupported_version",
+        "Unsupported lockfile version ({}). Please update initai",
+        "지원하지 않는 락파일 버전입니다 ({}). initai를 업데이트하세요",
+    ),
+    ("lockfile.serialize_failed", "Could not serialize lockfile", "락파일을 직렬화할 수 없습니다"),
+    // 토큰 추정
+    (
+        "tokens.estimate",
+        "~{} tokens (chars {}, words {}, mixed {})",
+        "약 {} 토큰 (chars {}, words {}, mixed {})",
+    ),
+    // 템플릿 이름
+    ("name.kind_name", "Template name", "템플릿 이름"),
+    ("name.kind_version", "Template version", "템플릿 버전"),
+    (
+        "name.at_sign",
+        "Template names cannot contain '@' (version separator): '{}'",
+        "템플릿 이름에 '@'를 사용할 수 없습니다 (버전 구분자): '{}'",
+    ),
+    (
+        "name.locale_suffix",
+        "{} cannot end in '.{}' because it would be read as a translation locale: '{}'",
+        "{}은(는) 번역본 로케일로 읽히는 '.{}'(으)로 끝날 수 없습니다: '{}'",
+    ),
+    (
+        "name.reserved",
+        "Reserved names cannot be used as template names: '{}'",
+        "예약된 이름은 템플릿 이름으로 사용할 수 없습니다: '{}'",
+    ),
+    (
+        "name.invalid_locale",
+        "Invalid locale (e.g. en, ko, en-US): '{}'",
+        "올바르지 않은 로케일입니다 (예: en, ko, en-US): '{}'",
+    ),
+    ("name.empty", "{} cannot be empty", "{}이(가) 비어 있습니다"),
+    ("name.too_long", "{} cannot be longer than {} characters", "{}은(는) {}자를 넘을 수 없습니다"),
+    ("name.control_char", "{} cannot contain control characters: {}", "{}에 제어 문자를 사용할 수 없습니다: {}"),
+    ("name.invalid_char", "{} cannot contain '{}': '{}'", "{}에 '{}' 문자를 사용할 수 없습니다: '{}'"),
+    ("name.dot_dot", "{} cannot contain '..': '{}'", "{}에 '..'를 사용할 수 없습니다: '{}'"),
+    (
+        "name.edge_dot",
+        "{} cannot start or end with '.': '{}'",
+        "{}은(는) '.'으로 시작하거나 끝날 수 없습니다: '{}'",
+    ),
+    (
+        "name.edge_space",
+        "{} cannot have leading or trailing whitespace: '{}'",
+        "{}의 앞뒤에 공백을 사용할 수 없습니다: '{}'",
+    ),
+    // 라이브러리 오류
+    (
+        "error.render",
+        "Could not render template '{}'",
+        "템플릿 '{}'을(를) 렌더링할 수 없습니다",
+    ),
+    ("error.io", "Could not access file: {}", "파일에 접근할 수 없습니다: {}"),
+    (
+        "error.read_only",
+        "This template store is read-only",
+        "읽기 전용 템플릿 저장소에는 쓸 수 없습니다",
+    ),
+    ("error.network", "Network request failed: {}", "네트워크 요청에 실패했습니다: {}"),
+    ("error.caused_by", "caused by: {}", "원인: {}"),
+    ("error.verbose_hint", "Run with --verbose for details.", "자세한 원인은 --verbose로 확인하세요."),
+];
@@ -0,0 +1,363 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::i18n::t;
+
+/// 템플릿 이름 최대 길이
+const MAX_NAME_LENGTH: usize = 100;
+
+/// 번역본 파일명 접미사로 인식하는 언어 코드 (ISO 639-1)
+///
+/// 형식만 보면 `api.js`, `1.0.rc`처럼 평범한 이름과 버전도 로케일로 읽히므로,
+/// 번역에 흔히 쓰는 언어만 번역본으로 취급
+const TRANSLATION_LANGUAGES: &[&str] = &[
+    "ar", "bg", "bn", "ca", "cs", "da", "de", "el", "en", "es", "et", "fa", "fi", "fr", "he",
+    "hi", "hr", "hu", "id", "it", "ja", "ko", "lt", "lv", "ms", "nb", "nl", "no", "pl", "pt",
+    "ro", "ru", "sk", "sl", "sr", "sv", "th", "tr", "uk", "vi", "zh",
+];
+
+/// Windows 예약 장치 이름
+const RESERVED_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// 검증된 템플릿 이름
+///
+/// 템플릿 디렉토리 안의 파일명으로 그대로 사용되므로 경로 구분자, `..`,
+/// 예약 이름, 제어 문자 등 디렉토리를 벗어나거나 파일명으로 쓸 수 없는 이름을 거부
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TemplateName(String);
+
+impl TemplateName {
+    pub fn new(name: &str) -> Result<Self> {
+        Self::validate(name).map_err(|e| Error::Validation(e.to_string()))?;
+        Ok(Self(name.to_string()))
+    }
+
+    fn validate(name: &str) -> anyhow::Result<()> {
+        validate_component(name, t!("name.kind_name"))?;
+
+        if name.contains('@') {
+            anyhow::bail!(t!("name.at_sign", name));
+        }
+
+        let stem = name.split('.').next().unwrap_or(name);
+        if RESERVED_NAMES.iter().any(|r| r.eq_ignore_ascii_case(stem)) {
+            anyhow::bail!(t!("name.reserved", name));
+        }
+
+        Ok(())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for TemplateName {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::new(s)
+    }
+}
+
+impl fmt::Display for TemplateName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for TemplateName {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for TemplateName {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for TemplateName {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+/// 템플릿 지정자 (`name`, `name@` 또는 `name@version`)
+///
+/// `name`은 설치된 최신 버전(버전 없는 파일 포함), `name@`은 버전 없는 파일
+/// (`name.md`), `name@version`은 해당 버전 파일만 가리킴
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateSpec {
+    pub name: TemplateName,
+    pub version: Option<String>,
+    /// 정확히 한 파일을 가리키는지 (`version`이 None이면 버전 없는 파일, false면 최신 버전)
+    pub exact: bool,
+}
+
+impl TemplateSpec {
+    /// 템플릿 지정자 생성 (버전이 없으면 최신 버전)
+    pub fn new(name: TemplateName, version: Option<String>) -> Self {
+        let exact = version.is_some();
+        Self {
+            name,
+            version,
+            exact,
+        }
+    }
+
+    /// 정확히 한 파일을 가리키는 지정자 생성 (버전이 없으면 버전 없는 파일)
+    pub fn exact(name: TemplateName, version: Option<String>) -> Self {
+        Self {
+            name,
+            version,
+            exact: true,
+        }
+    }
+
+    /// `name`, `name@`, `name@version` 형식의 지정자 파싱 및 검증
+    pub fn parse(spec: &str) -> Result<Self> {
+        let (name, version) = match spec.split_once('@') {
+            Some((name, "")) => return Ok(Self::exact(TemplateName::new(name)?, None)),
+            Some((name, version)) => {
+                validate_component(version, t!("name.kind_version"))
+                    .map_err(|e| Error::Validation(e.to_string()))?;
+                (name, Some(version.to_string()))
+            }
+            None => (spec, None),
+        };
+
+        Ok(Self::new(TemplateName::new(name)?, version))
+    }
+
+    /// 버전을 지정하지 않아 최신 버전을 가리키는지 확인
+    pub fn is_latest(&self) -> bool {
+        !self.exact && self.version.is_none()
+    }
+
+    /// 템플릿이 지정자가 가리키는 버전인지 확인 (최신 버전 지정자는 모든 버전과 일치)
+    pub fn matches_version(&self, version: Option<&str>) -> bool {
+        self.is_latest() || self.version.as_deref() == version
+    }
+
+    /// 템플릿 디렉토리 내 파일명 (확장자 제외, `name` 또는 `name@version`)
+    pub fn file_stem(&self) -> String {
+        match &self.version {
+            Some(version) => format!("{}@{}", self.name, version),
+            None => self.name.to_string(),
+        }
+    }
+
+    /// 템플릿 디렉토리 내 파일명
+    pub fn file_name(&self) -> String {
+        format!("{}.md", self.file_stem())
+    }
+}
+
+impl FromStr for TemplateSpec {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl From<TemplateName> for TemplateSpec {
+    fn from(name: TemplateName) -> Self {
+        Self::new(name, None)
+    }
+}
+
+impl fmt::Display for TemplateSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.version {
+            Some(version) => write!(f, "{}@{}", self.name, version),
+            None if self.exact => write!(f, "{}@", self.name),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+/// 로케일 형식 확인 (`en`, `ko`, `en-US`, `pt_BR`, `es-419` 등)
+///
+/// ISO 639-1 언어 코드(소문자 2자)와 선택적인 지역 코드(영문자 2자 또는 숫자 3자)만 허용
+pub fn is_locale(value: &str) -> bool {
+    let (language, region) = split_locale(value);
+
+    language.len() == 2
+        && language.bytes().all(|b| b.is_ascii_lowercase())
+        && region.is_none_or(|region| {
+            (region.len() == 2 && region.bytes().all(|b| b.is_ascii_alphabetic()))
+                || (region.len() == 3 && region.bytes().all(|b| b.is_ascii_digit()))
+        })
+}
+
+/// 번역본 파일명(`이름.<로케일>.md`)의 로케일 접미사인지 확인
+///
+/// 로케일 형식이면서 언어가 번역본 언어 목록에 있는 경우만 해당
+pub fn is_translation_locale(value: &str) -> bool {
+    is_locale(value) && TRANSLATION_LANGUAGES.contains(&split_locale(value).0)
+}
+
+/// 로케일을 언어와 지역으로 분리
+fn split_locale(value: &str) -> (&str, Option<&str>) {
+    match value.split_once(['-', '_']) {
+        Some((language, region)) => (language, Some(region)),
+        None => (value, None),
+    }
+}
+
+/// `name[@version][.locale]` 형식의 템플릿 파일 지정자 파싱 (번역본 설치, 파일명 해석)
+///
+/// 마지막 `.` 뒤가 번역본 언어의 로케일(`ko`, `pt-BR` 등)일 때만 번역본으로 취급하고,
+/// 그 밖의 접미사(`Backend.dev`, `api.js`)는 이름의 일부
+pub fn parse_localized(value: &str) -> Result<(TemplateSpec, Option<String>)> {
+    match value.rsplit_once('.') {
+        Some((base, suffix)) if is_translation_locale(suffix) => {
+            Ok((TemplateSpec::parse(base)?, Some(suffix.to_string())))
+        }
+        _ => Ok((TemplateSpec::parse(value)?, None)),
+    }
+}
+
+/// 로케일 인수 검증
+pub fn parse_locale(value: &str) -> Result<String> {
+    if !is_locale(value) {
+        return Err(Error::Validation(t!("name.invalid_locale", value)));
+    }
+    Ok(value.to_string())
+}
+
+/// 파일명 구성 요소로 안전한지 검증
+fn validate_component(value: &str, kind: &str) -> anyhow::Result<()> {
+    if value.is_empty() {
+        anyhow::bail!(t!("name.empty", kind));
+    }
+    if value.chars().count() > MAX_NAME_LENGTH {
+        anyhow::bail!(t!("name.too_long", kind, MAX_NAME_LENGTH));
+    }
+    if value.chars().any(|c| c.is_control()) {
+        anyhow::bail!(t!("name.control_char", kind, format!("{:?}", value)));
+    }
+    if let Some(c) = value
+        .chars()
+        .find(|c| matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|'))
+    {
+        anyhow::bail!(t!("name.invalid_char", kind, c, value));
+    }
+    if value.contains("..") {
+        anyhow::bail!(t!("name.dot_dot", kind, value));
+    }
+    if value.starts_with('.') || value.ends_with('.') {
+        anyhow::bail!(t!("name.edge_dot", kind, value));
+    }
+    if value.trim() != value {
+        anyhow::bail!(t!("name.edge_space", kind, value));
+    }
+    // 번역본 파일명(`이름.<로케일>.md`)과 구분할 수 없는 접미사
+    if let Some((_, suffix)) = value
+        .rsplit_once('.')
+        .filter(|(_, s)| is_translation_locale(s))
+    {
+        anyhow::bail!(t!("name.locale_suffix", kind, suffix, value));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_names() {
+        for name in [
+            "Programming-Team",
+            "my_rules",
+            "팀규칙",
+            "v1.rules",
+            "Backend.dev",
+            "api.js",
+            "app.ts",
+        ] {
+            assert!(TemplateName::new(name).is_ok(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_rejects_traversal_and_reserved_names() {
+        for name in [
+            "",
+            "..",
+            "../../etc/x",
+            "a/b",
+            "a\\b",
+            "C:evil",
+            ".hidden",
+            "name.",
+            "CON",
+            "nul.txt",
+            "tab\tname",
+            " padded",
+            "a@b",
+            "rules.en-US",
+            "notes.ko",
+        ] {
+            assert!(TemplateName::new(name).is_err(), "{:?}", name);
+        }
+    }
+
+    #[test]
+    fn test_template_spec() {
+        let spec = TemplateSpec::parse("Programming-Team@1.2").unwrap();
+        assert_eq!(spec.name, "Programming-Team");
+        assert_eq!(spec.version.as_deref(), Some("1.2"));
+        assert_eq!(spec.file_name(), "Programming-Team@1.2.md");
+
+        assert!(TemplateSpec::parse("Team@../../x").is_err());
+        assert!(TemplateSpec::parse("@1.0").is_err());
+        assert!(TemplateSpec::parse("Team@1.0.rc").is_ok());
+        assert!(TemplateSpec::parse("Team@1.0-rc").is_ok());
+        assert!(TemplateSpec::parse("Team@1.0.en").is_err());
+
+        // 버전 없는 파일을 가리키는 `name@`과 최신 버전을 가리키는 `name` 구분
+        let latest = TemplateSpec::parse("Team").unwrap();
+        let unversioned = TemplateSpec::parse("Team@").unwrap();
+        assert!(latest.is_latest() && !unversioned.is_latest());
+        assert!(latest.matches_version(Some("2.0")));
+        assert!(!unversioned.matches_version(Some("2.0")));
+        assert!(unversioned.matches_version(None));
+        assert_eq!(unversioned.to_string(), "Team@");
+        assert_eq!(unversioned.file_name(), "Team.md");
+        assert_eq!(TemplateSpec::parse("Team@").unwrap(), unversioned);
+    }
+
+    #[test]
+    fn test_locale() {
+        for locale in ["en", "ko", "en-US", "pt_BR", "es-419"] {
+            assert!(is_locale(locale), "{}", locale);
+        }
+        for locale in ["", "e", "EN", "dev", "old", "rules", "en-", "en-U", "en-USA", "1.0", "en/US"] {
+            assert!(!is_locale(locale), "{}", locale);
+        }
+
+        let (spec, locale) = parse_localized("Rules@1.0.pt-BR").unwrap();
+        assert_eq!(spec.to_string(), "Rules@1.0");
+        assert_eq!(locale.as_deref(), Some("pt-BR"));
+        let (spec, locale) = parse_localized("api.js").unwrap();
+        assert_eq!((spec.name.as_str(), locale), ("api.js", None));
+
+        // 번역본 접미사는 번역본 언어 목록에 있는 언어만
+        for suffix in ["en", "ko", "pt-BR", "zh_TW"] {
+            assert!(is_translation_locale(suffix), "{}", suffix);
+        }
+        for suffix in ["js", "ts", "rc", "md", "js-US"] {
+            assert!(!is_translation_locale(suffix), "{}", suffix);
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use anyhow::Result;
+use colored::Colorize;
+use dialoguer::Confirm;
+use serde::Serialize;
+use std::fmt::Display;
+use std::io::{IsTerminal, Write};
+
+use crate::cli::OutputFormat;
+use init_ai::error::{self, Error};
+use init_ai::i18n::t;
+
+/// 전역 출력 옵션 (`--format`, `--quiet`, `--yes`)
+#[derive(Debug, Clone, Copy)]
+pub struct Output {
+    pub format: OutputFormat,
+    pub quiet: bool,
+    /// 확인 질문에 모두 예로 답함
+    pub yes: bool,
+}
+
+impl Output {
+    pub fn is_json(&self) -> bool {
+        self.format == OutputFormat::Json
+    }
+
+    /// 안내 메시지 출력 (`--quiet`이거나 JSON 형식이면 생략)
+    pub fn info(&self, message: impl Display) {
+        if !self.quiet && !self.is_json() {
+            println!("{}", message);
+        }
+    }
+
+    /// 경고를 표준 오류에 출력 (`--quiet`이거나 JSON 형식이면 생략, JSON은 결과에 경고를 담음)
+    pub fn warn(&self, message: impl Display) {
+        if !self.quiet && !self.is_json() {
+            eprintln!("{} {}", "⚠️".yellow(), message);
+        }
+    }
+
+    /// 사용자에게 질문할 수 있는지 (JSON 형식이 아니고 표준 입력과 질문을 표시할 표준 오류가 모두 터미널)
+    pub fn can_prompt(&self) -> bool {
+        !self.is_json() && std::io::stdin().is_terminal() && std::io::stderr().is_terminal()
+    }
+
+    /// 확인 질문 (`--yes`면 묻지 않고 예)
+    ///
+    /// 질문할 수 없으면 입력 오류 대신 `Error::Conflict`로 중단. JSON 형식에서는 묻지 않으므로 `--yes` 필요
+    pub fn confirm(&self, prompt: &str, default: bool) -> Result<bool> {
+        if self.yes {
+            return Ok(true);
+        }
+        if self.is_json() {
+            return Err(Error::Conflict(t!("common.confirm_json", prompt)).into());
+        }
+        if !self.can_prompt() {
+            return Err(Error::Conflict(t!("common.confirm_required", prompt)).into());
+        }
+
+        Ok(Confirm::new().with_prompt(prompt).default(default).interact()?)
+    }
+
+    /// 결과를 출력한 뒤 실패로 끝낼 때의 오류
+    ///
+    /// JSON 형식이면 결과 JSON을 이미 출력했으므로 오류 JSON을 다시 출력하지 않도록 `Reported`로 감쌈
+    pub fn fail(&self, error: Error) -> anyhow::Error {
+        if self.is_json() {
+            Reported(error).into()
+        } else {
+            error.into()
+        }
+    }
+
+    /// JSON 값 출력 (파이프가 먼저 닫혀도 패닉하지 않도록 직접 기록)
+    pub fn json(&self, value: &impl Serialize) -> Result<()> {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, value)?;
+        writeln!(stdout)?;
+        Ok(())
+    }
+}
+
+/// 결과 JSON을 이미 출력한 명령의 오류 (종료 코드는 감싼 오류를 따름)
+#[derive(Debug)]
+pub struct Reported(Error);
+
+impl Display for Reported {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for Reported {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+/// 명령 사용법 오류 (clap 인수 오류와 같은 종료 코드 2로 종료)
+#[derive(Debug)]
+pub struct Usage(pub String);
+
+impl Display for Usage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Usage {}
+
+/// 오류의 종료 코드 (사용법 오류는 `EXIT_USAGE`)
+pub fn exit_code(e: &anyhow::Error) -> i32 {
+    if e.downcast_ref::<Usage>().is_some() {
+        error::EXIT_USAGE
+    } else {
+        error::exit_code(e)
+    }
+}
+
+/// 원인 체인 메시지 (`Error::Other`처럼 감싼 오류와 메시지가 같은 단계는 한 번만)
+pub fn chain_messages(e: &anyhow::Error) -> Vec<String> {
+    let mut messages: Vec<String> = Vec::new();
+    for cause in e.chain() {
+        let message = cause.to_string();
+        if messages.last() != Some(&message) {
+            messages.push(message);
+        }
+    }
+    messages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fail_keeps_exit_code() {
+        let text = Output { format: OutputFormat::Text, quiet: false, yes: false };
+        let json = Output { format: OutputFormat::Json, ..text };
+
+        let e = text.fail(Error::Validation("x".to_string()));
+        assert!(e.downcast_ref::<Reported>().is_none());
+        assert_eq!(error::exit_code(&e), error::EXIT_VALIDATION);
+
+        // JSON 형식에서는 오류 JSON을 생략하도록 표시하되 종료 코드는 유지
+        let e = json.fail(Error::Validation("x".to_string()));
+        assert!(e.downcast_ref::<Reported>().is_some());
+        assert_eq!(error::exit_code(&e), error::EXIT_VALIDATION);
+        assert!(json.confirm("?", false).is_err());
+    }
+
+    #[test]
+    fn test_usage_exit_code() {
+        let e: anyhow::Error = Usage("usage".to_string()).into();
+        assert_eq!(exit_code(&e), error::EXIT_USAGE);
+        assert_eq!(exit_code(&e.context("context")), error::EXIT_USAGE);
+        assert_eq!(exit_code(&Error::Cancelled.into()), error::EXIT_CANCELLED);
+    }
+
+    #[test]
+    fn test_chain_messages() {
+        let other = Error::Other(anyhow::anyhow!("inner").context("outer"));
+        let e = anyhow::Error::new(other).context("command");
+        assert_eq!(chain_messages(&e), ["command", "outer", "inner"]);
+        assert_eq!(exit_code(&e), error::EXIT_FAILURE);
+    }
+}
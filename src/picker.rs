@@ -0,0 +1,295 @@
+use colored::Colorize;
+use console::{Key, Term};
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+use std::io;
+
+use init_ai::i18n::t;
+
+/// 한 번에 표시할 후보 수
+const VISIBLE_ITEMS: usize = 10;
+/// 미리보기 줄 수
+pub const PREVIEW_LINES: usize = 8;
+
+/// 선택 후보
+#[derive(Debug, Clone, Default)]
+pub struct Item {
+    pub name: String,
+    pub description: String,
+    pub tags: Vec<String>,
+    /// 미리보기 영역에 표시할 줄
+    pub preview: Vec<String>,
+}
+
+/// 키 입력 처리 결과
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// 입력을 더 받아야 함
+    Pending,
+    /// 선택 완료 (후보 인덱스, 선택한 순서대로)
+    Done(Vec<usize>),
+    /// 취소
+    Cancelled,
+}
+
+/// 퍼지 검색 선택기 상태
+///
+/// 이름, 설명, 태그를 검색하고 Tab으로 여러 후보를 선택. 터미널과 분리해서
+/// 키 입력만으로 동작을 확인할 수 있음
+pub struct Picker {
+    items: Vec<Item>,
+    query: String,
+    /// 검색 결과 안에서 강조된 위치
+    cursor: usize,
+    /// 선택한 후보 인덱스 (선택한 순서)
+    selected: Vec<usize>,
+    matcher: SkimMatcherV2,
+}
+
+impl Picker {
+    pub fn new(items: Vec<Item>) -> Self {
+        Self {
+            items,
+            query: String::new(),
+            cursor: 0,
+            selected: Vec::new(),
+            matcher: SkimMatcherV2::default(),
+        }
+    }
+
+    /// 검색어와 일치하는 후보 인덱스 (점수 높은 순, 같으면 원래 순서)
+    ///
+    /// 공백으로 나눈 검색어가 모두 일치해야 하며, 이름에서 일치하면 설명이나 태그보다 우선
+    pub fn matches(&self) -> Vec<usize> {
+        let terms: Vec<&str> = self.query.split_whitespace().collect();
+        let mut scored: Vec<(i64, usize)> = self
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(index, item)| {
+                let haystack = format!("{} {} {}", item.name, item.description, item.tags.join(" "));
+                let mut total = 0;
+                for term in &terms {
+                    let name = self.matcher.fuzzy_match(&item.name, term).map(|s| s * 2);
+                    total += name.max(self.matcher.fuzzy_match(&haystack, term))?;
+                }
+                Some((total, index))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        scored.into_iter().map(|(_, index)| index).collect()
+    }
+
+    /// 강조된 후보 인덱스
+    pub fn current(&self) -> Option<usize> {
+        self.matches().get(self.cursor).copied()
+    }
+
+    /// 키 입력 처리
+    pub fn handle(&mut self, key: Key) -> Outcome {
+        let count = self.matches().len();
+        match key {
+            Key::Escape | Key::CtrlC => return Outcome::Cancelled,
+            Key::Enter => {
+                if !self.selected.is_empty() {
+                    return Outcome::Done(self.selected.clone());
+                }
+                if let Some(index) = self.current() {
+                    return Outcome::Done(vec![index]);
+                }
+            }
+            Key::ArrowUp if count > 0 => self.cursor = (self.cursor + count - 1) % count,
+            Key::ArrowDown if count > 0 => self.cursor = (self.cursor + 1) % count,
+            Key::Tab => {
+                if let Some(index) = self.current() {
+                    match self.selected.iter().position(|&i| i == index) {
+                        Some(position) => {
+                            self.selected.remove(position);
+                        }
+                        None => self.selected.push(index),
+                    }
+                    if count > 0 {
+                        self.cursor = (self.cursor + 1) % count;
+                    }
+                }
+            }
+            Key::Backspace => {
+                self.query.pop();
+                self.cursor = 0;
+            }
+            Key::Char(c) if !c.is_control() => {
+                self.query.push(c);
+                self.cursor = 0;
+            }
+            _ => {}
+        }
+        Outcome::Pending
+    }
+
+    /// 화면에 표시할 줄 (검색어, 후보 목록, 미리보기)
+    fn render(&self, prompt: &str, width: usize) -> Vec<String> {
+        let fit = |line: String| console::truncate_str(&line, width, "…").into_owned();
+        let matches = self.matches();
+        let mut lines = vec![
+            format!("{} {}", format!("{}:", prompt).bold(), self.query),
+            fit(t!("interactive.picker_help").to_string()).dimmed().to_string(),
+        ];
+
+        if matches.is_empty() {
+            lines.push(format!("  {}", t!("interactive.picker_no_match")).yellow().to_string());
+            return lines;
+        }
+
+        let start = self.cursor.saturating_sub(VISIBLE_ITEMS - 1);
+        for (position, &index) in matches.iter().enumerate().skip(start).take(VISIBLE_ITEMS) {
+            let item = &self.items[index];
+            let marker = if self.selected.contains(&index) { "[x]" } else { "[ ]" };
+            let mut line = format!("{} {}", marker, item.name);
+            if !item.description.is_empty() {
+                line.push_str(&format!("  {}", item.description));
+            }
+            if !item.tags.is_empty() {
+                let tags: Vec<String> = item.tags.iter().map(|tag| format!("#{}", tag)).collect();
+                line.push_str(&format!("  {}", tags.join(" ")));
+            }
+            let line = fit(format!("  {}", line));
+            lines.push(if position == self.cursor {
+                format!(">{}", &line[1..]).cyan().bold().to_string()
+            } else {
+                line
+            });
+        }
+        if matches.len() > start + VISIBLE_ITEMS {
+            let hidden = matches.len() - start - VISIBLE_ITEMS;
+            lines.push(format!("  {}", t!("interactive.picker_more", hidden)).dimmed().to_string());
+        }
+
+        if let Some(index) = self.current() {
+            lines.push(fit(format!("── {} ", self.items[index].name)).dimmed().to_string());
+            for line in &self.items[index].preview {
+                lines.push(fit(format!("  {}", line)).dimmed().to_string());
+            }
+        }
+        lines
+    }
+}
+
+/// 퍼지 검색으로 후보 선택 (선택한 후보 인덱스, 취소하면 None)
+pub fn pick(prompt: &str, items: Vec<Item>) -> io::Result<Option<Vec<usize>>> {
+    let term = Term::stderr();
+    if !term.is_term() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotConnected,
+            t!("interactive.picker_not_terminal"),
+        ));
+    }
+
+    let mut picker = Picker::new(items);
+    let mut drawn = 0;
+    term.hide_cursor()?;
+    let outcome = (|| loop {
+        let width = term.size().1 as usize;
+        term.clear_last_lines(drawn)?;
+        let lines = picker.render(prompt, width.max(20));
+        for line in &lines {
+            term.write_line(line)?;
+        }
+        drawn = lines.len();
+
+        match picker.handle(term.read_key()?) {
+            Outcome::Pending => continue,
+            outcome => return io::Result::Ok(outcome),
+        }
+    })();
+    // 읽기에 실패해도 커서는 되돌림
+    term.show_cursor()?;
+    let outcome = outcome?;
+    term.clear_last_lines(drawn)?;
+
+    Ok(match outcome {
+        Outcome::Done(indices) => {
+            let names: Vec<&str> = indices.iter().map(|&i| picker.items[i].name.as_str()).collect();
+            term.write_line(&format!("{} · {}", prompt.bold(), names.join(", ").green()))?;
+            Some(indices)
+        }
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(name: &str, description: &str, tags: &[&str]) -> Item {
+        Item {
+            name: name.to_string(),
+            description: description.to_string(),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            preview: Vec::new(),
+        }
+    }
+
+    fn picker() -> Picker {
+        Picker::new(vec![
+            item("Programming-Team", "AI 개발 팀 규칙", &["team"]),
+            item("Code-Review", "리뷰만 하는 AI 리뷰어 규칙", &["review", "quality"]),
+            item("Frontend", "웹 프론트엔드 팀 규칙", &["web", "react"]),
+        ])
+    }
+
+    fn type_query(picker: &mut Picker, query: &str) {
+        for c in query.chars() {
+            picker.handle(Key::Char(c));
+        }
+    }
+
+    #[test]
+    fn test_picker_search() {
+        let mut picker = picker();
+        assert_eq!(picker.matches(), [0, 1, 2]);
+
+        // 이름, 설명, 태그 모두 검색
+        type_query(&mut picker, "cr");
+        assert_eq!(picker.matches()[0], 1);
+        picker.query.clear();
+        type_query(&mut picker, "react");
+        assert_eq!(picker.matches(), [2]);
+        picker.query.clear();
+        type_query(&mut picker, "리뷰어 quality");
+        assert_eq!(picker.matches(), [1]);
+
+        type_query(&mut picker, "zzz");
+        assert!(picker.matches().is_empty());
+        assert_eq!(picker.handle(Key::Enter), Outcome::Pending);
+        assert_eq!(picker.handle(Key::Escape), Outcome::Cancelled);
+    }
+
+    #[test]
+    fn test_picker_multi_select() {
+        let mut picker = picker();
+        assert_eq!(picker.handle(Key::ArrowUp), Outcome::Pending);
+        assert_eq!(picker.current(), Some(2));
+
+        // Tab으로 선택한 순서 유지, 다시 누르면 해제
+        picker.handle(Key::Tab);
+        picker.handle(Key::ArrowDown);
+        picker.handle(Key::Tab);
+        assert_eq!(picker.current(), Some(2));
+        picker.handle(Key::Tab);
+        picker.handle(Key::ArrowUp);
+        picker.handle(Key::Tab);
+        assert_eq!(picker.handle(Key::Enter), Outcome::Done(vec![1, 2]));
+
+        // 선택 없이 Enter면 강조된 후보
+        let mut picker = picker_with_cursor(1);
+        assert_eq!(picker.handle(Key::Enter), Outcome::Done(vec![1]));
+    }
+
+    fn picker_with_cursor(cursor: usize) -> Picker {
+        let mut picker = picker();
+        for _ in 0..cursor {
+            picker.handle(Key::ArrowDown);
+        }
+        picker
+    }
+}
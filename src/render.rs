@@ -0,0 +1,779 @@
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use crate::config::Config;
+use crate::error::{Error, Result};
+use crate::i18n::t;
+use crate::name::{TemplateName, TemplateSpec};
+use crate::store::TemplateStore;
+
+/// 프론트 매터 구분자
+const FRONT_MATTER_DELIMITER: &str = "+++";
+
+/// include 최대 깊이
+const MAX_INCLUDE_DEPTH: usize = 16;
+
+/// 템플릿 메타데이터 (파일 앞부분의 `+++`로 감싼 TOML 프론트 매터)
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct TemplateMetadata {
+    pub description: Option<String>,
+    pub version: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// 상위 템플릿 이름 (상위 템플릿 내용 뒤에 본문이 이어짐)
+    pub extends: Option<String>,
+    /// 렌더링 결과의 최대 추정 토큰 수
+    pub max_tokens: Option<usize>,
+    /// 변수 기본값
+    #[serde(default)]
+    pub variables: BTreeMap<String, String>,
+    /// 기본 파일의 작성 언어 (번역본이 없을 때 안내에 사용)
+    pub locale: Option<String>,
+}
+
+/// 템플릿 내용을 메타데이터와 본문으로 분리
+pub fn split_front_matter(content: &str) -> Result<(TemplateMetadata, &str)> {
+    let (front_matter, body) = split_front_matter_raw(content)?;
+    let metadata = match front_matter {
+        Some(front_matter) => {
+            toml::from_str(front_matter).context(t!("render.front_matter_invalid"))?
+        }
+        None => TemplateMetadata::default(),
+    };
+
+    Ok((metadata, body))
+}
+
+/// 프론트 매터 원문(구분자 제외, 없으면 None)과 본문 분리
+pub(crate) fn split_front_matter_raw(content: &str) -> Result<(Option<&str>, &str)> {
+    let Some(rest) = strip_delimiter_line(content) else {
+        return Ok((None, content));
+    };
+
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == FRONT_MATTER_DELIMITER {
+            return Ok((Some(&rest[..offset]), &rest[offset + line.len()..]));
+        }
+        offset += line.len();
+    }
+
+    Err(Error::Validation(t!("render.front_matter_unclosed", FRONT_MATTER_DELIMITER)))
+}
+
+/// 첫 줄이 프론트 매터 구분자이면 그 다음 위치부터 반환
+fn strip_delimiter_line(content: &str) -> Option<&str> {
+    let rest = content.strip_prefix(FRONT_MATTER_DELIMITER)?;
+    rest.strip_prefix("\r\n").or_else(|| rest.strip_prefix('\n'))
+}
+
+/// 템플릿 구문 노드
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    Text(String),
+    /// `{{ name }}` (정의되지 않은 변수면 원래 태그 `source`를 그대로 출력)
+    Variable { name: String, source: String },
+    /// `{{> template }}`
+    Include(String),
+    /// `{{#if name}} ... {{else}} ... {{/if}}`
+    If {
+        condition: String,
+        then_branch: Vec<Node>,
+        else_branch: Vec<Node>,
+    },
+}
+
+/// 본문의 펜스 코드 블록 범위 (여는 줄부터 닫는 줄까지, 닫히지 않으면 본문 끝까지)
+///
+/// 코드 블록 안의 `{{ ... }}`는 예시 코드로 보고 태그로 처리하지 않음
+pub(crate) fn code_blocks(body: &str) -> Vec<Range<usize>> {
+    let mut blocks = Vec::new();
+    // (시작 위치, 펜스 문자, 펜스 길이)
+    let mut open: Option<(usize, char, usize)> = None;
+    let mut offset = 0;
+
+    for line in body.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+
+        let trimmed = line.trim_start_matches(' ');
+        if line.len() - trimmed.len() > 3 {
+            continue;
+        }
+        let Some(fence) = trimmed.chars().next().filter(|c| matches!(c, '`' | '~')) else {
+            continue;
+        };
+        let length = trimmed.chars().take_while(|&c| c == fence).count();
+        if length < 3 {
+            continue;
+        }
+
+        match open {
+            None => open = Some((start, fence, length)),
+            Some((block_start, open_fence, open_length))
+                if fence == open_fence
+                    && length >= open_length
+                    && trimmed[length..].trim().is_empty() =>
+            {
+                blocks.push(block_start..offset);
+                open = None;
+            }
+            Some(_) => {}
+        }
+    }
+
+    if let Some((start, ..)) = open {
+        blocks.push(start..body.len());
+    }
+    blocks
+}
+
+/// 본문의 인라인 코드 범위 (펜스 코드 블록 밖에서 같은 길이의 백틱으로 닫힌 경우만)
+///
+/// 닫는 백틱은 같은 문단 안에서만 찾고, 닫히지 않은 백틱은 일반 문자로 취급
+pub(crate) fn code_spans(body: &str, blocks: &[Range<usize>]) -> Vec<Range<usize>> {
+    let mut spans = Vec::new();
+    let mut position = 0;
+
+    while let Some(found) = body[position..].find('`') {
+        let start = position + found;
+        if let Some(end) = code_block_end(blocks, start) {
+            position = end;
+            continue;
+        }
+
+        let length = body[start..].chars().take_while(|&c| c == '`').count();
+        let content_start = start + length;
+        let paragraph_end = body[content_start..]
+            .find("\n\n")
+            .map_or(body.len(), |i| content_start + i);
+        let limit = blocks
+            .iter()
+            .map(|b| b.start)
+            .filter(|&s| s > start)
+            .fold(paragraph_end, usize::min);
+
+        // 같은 길이의 백틱 묶음 찾기
+        let mut search = content_start;
+        let mut close = None;
+        while let Some(i) = body[search..limit].find('`') {
+            let run_start = search + i;
+            let run = body[run_start..limit].chars().take_while(|&c| c == '`').count();
+            if run == length {
+                close = Some(run_start + run);
+                break;
+            }
+            search = run_start + run;
+        }
+
+        match close {
+            Some(end) => {
+                spans.push(start..end);
+                position = end;
+            }
+            None => position = content_start,
+        }
+    }
+
+    spans
+}
+
+/// 태그를 처리하지 않는 범위 (펜스 코드 블록과 인라인 코드, 시작 위치순)
+pub(crate) fn literal_ranges(body: &str) -> Vec<Range<usize>> {
+    let mut ranges = code_blocks(body);
+    ranges.extend(code_spans(body, &ranges.clone()));
+    ranges.sort_by_key(|r| r.start);
+    ranges
+}
+
+/// `position`이 코드 블록(또는 인라인 코드) 안이면 그 범위의 끝 위치
+pub(crate) fn code_block_end(blocks: &[Range<usize>], position: usize) -> Option<usize> {
+    blocks.iter().find(|b| b.contains(&position)).map(|b| b.end)
+}
+
+/// 처리할 수 있는 태그인지 (변수, include, 조건문)
+///
+/// 그 밖의 태그는 오류 대신 원래 내용 그대로 출력
+pub(crate) fn is_known_tag(tag: &str) -> bool {
+    tag.starts_with('>')
+        || tag.starts_with("#if ")
+        || tag == "else"
+        || tag == "/if"
+        || is_identifier(tag)
+}
+
+/// 템플릿 본문 파싱
+///
+/// 펜스 코드 블록과 인라인 코드 안의 내용, 알 수 없는 태그는 처리하지 않고 그대로 출력
+pub fn parse(body: &str) -> Result<Vec<Node>> {
+    let blocks = literal_ranges(body);
+    // (조건, then 노드, else 노드, else 진입 여부)
+    let mut stack: Vec<(String, Vec<Node>, Vec<Node>, bool)> = Vec::new();
+    let mut nodes: Vec<Node> = Vec::new();
+    let mut text = String::new();
+    let mut rest = body;
+
+    fn current<'a>(
+        stack: &'a mut [(String, Vec<Node>, Vec<Node>, bool)],
+        nodes: &'a mut Vec<Node>,
+    ) -> &'a mut Vec<Node> {
+        match stack.last_mut() {
+            Some((_, _, else_branch, true)) => else_branch,
+            Some((_, then_branch, _, false)) => then_branch,
+            None => nodes,
+        }
+    }
+
+    while let Some(start) = rest.find("{{") {
+        // 코드 블록은 그대로 출력
+        let offset = body.len() - rest.len();
+        if let Some(end) = code_block_end(&blocks, offset + start) {
+            text.push_str(&rest[..end - offset]);
+            rest = &rest[end - offset..];
+            continue;
+        }
+
+        // `\{{`는 그대로 출력
+        if rest[..start].ends_with('\\') {
+            text.push_str(&rest[..start - 1]);
+            text.push_str("{{");
+            rest = &rest[start + 2..];
+            continue;
+        }
+
+        text.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after
+            .find("}}")
+            .ok_or_else(|| Error::Validation(t!("render.unclosed_tag").to_string()))?;
+        let source = &rest[start..start + 2 + end + 2];
+        let tag = after[..end].trim();
+        rest = &after[end + 2..];
+
+        // 알 수 없는 태그는 일반 문자로 출력
+        if !is_known_tag(tag) {
+            text.push_str(source);
+            continue;
+        }
+
+        if !text.is_empty() {
+            current(&mut stack, &mut nodes).push(Node::Text(std::mem::take(&mut text)));
+        }
+
+        if let Some(name) = tag.strip_prefix('>') {
+            current(&mut stack, &mut nodes).push(Node::Include(name.trim().to_string()));
+        } else if let Some(condition) = tag.strip_prefix("#if ") {
+            stack.push((condition.trim().to_string(), Vec::new(), Vec::new(), false));
+        } else if tag == "else" {
+            match stack.last_mut() {
+                Some((_, _, _, in_else @ false)) => *in_else = true,
+                _ => return Err(Error::Validation(t!("render.else_without_if").to_string())),
+            }
+        } else if tag == "/if" {
+            let (condition, then_branch, else_branch, _) = stack
+                .pop()
+                .ok_or_else(|| Error::Validation(t!("render.endif_without_if").to_string()))?;
+            current(&mut stack, &mut nodes).push(Node::If {
+                condition,
+                then_branch,
+                else_branch,
+            });
+        } else {
+            current(&mut stack, &mut nodes).push(Node::Variable {
+                name: tag.to_string(),
+                source: source.to_string(),
+            });
+        }
+    }
+
+    text.push_str(rest);
+    if !text.is_empty() {
+        current(&mut stack, &mut nodes).push(Node::Text(text));
+    }
+
+    if let Some((condition, ..)) = stack.last() {
+        return Err(Error::Validation(t!(
+            "render.if_unclosed",
+            format!("{{{{#if {}}}}}", condition)
+        )));
+    }
+
+    Ok(nodes)
+}
+
+/// 템플릿 내용의 프론트 매터와 구문 검증
+pub fn validate_content(content: &str) -> Result<TemplateMetadata> {
+    let (metadata, body) = split_front_matter(content)?;
+    parse(body)?;
+
+    Ok(metadata)
+}
+
+/// 템플릿 내용에서 다른 템플릿 참조(include, extends)의 이름 변경
+pub fn replace_template_references(
+    content: &str,
+    old_name: &TemplateName,
+    new_name: &TemplateName,
+) -> String {
+    // (여는 구분자 줄, 프론트 매터 TOML, 닫는 구분자 줄부터 시작하는 나머지)
+    let mut sections = None;
+    if let Some(rest) = strip_delimiter_line(content) {
+        let mut offset = 0;
+        for line in rest.split_inclusive('\n') {
+            if line.trim_end() == FRONT_MATTER_DELIMITER {
+                let opening = &content[..content.len() - rest.len()];
+                sections = Some((opening, &rest[..offset], &rest[offset..]));
+                break;
+            }
+            offset += line.len();
+        }
+    }
+
+    let mut output = String::with_capacity(content.len());
+    let body = match sections {
+        Some((opening, front_matter, rest)) => {
+            let closing = rest.split_inclusive('\n').next().unwrap_or_default();
+            output.push_str(opening);
+            output.push_str(&rename_extends(front_matter, old_name, new_name));
+            output.push_str(closing);
+            &rest[closing.len()..]
+        }
+        None => content,
+    };
+
+    // 본문의 include 태그 변경 (코드 블록, 인라인 코드 제외)
+    let blocks = literal_ranges(body);
+    let mut rest = body;
+    while let Some(start) = rest.find("{{") {
+        let offset = body.len() - rest.len();
+        if let Some(end) = code_block_end(&blocks, offset + start) {
+            output.push_str(&rest[..end - offset]);
+            rest = &rest[end - offset..];
+            continue;
+        }
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        let tag = &after[..end];
+
+        output.push_str(&rest[..start + 2]);
+        match tag.trim().strip_prefix('>').map(str::trim) {
+            Some(spec)
+                if !rest[..start].ends_with('\\')
+                    && references(spec, old_name) =>
+            {
+                output.push_str(&tag.replacen(spec, &rename_spec(spec, new_name), 1));
+            }
+            _ => output.push_str(tag),
+        }
+        output.push_str("}}");
+        rest = &after[end + 2..];
+    }
+    output.push_str(rest);
+
+    output
+}
+
+/// 프론트 매터의 extends 값 변경 (주석과 서식 유지, TOML이 올바르지 않으면 그대로)
+fn rename_extends(front_matter: &str, old_name: &TemplateName, new_name: &TemplateName) -> String {
+    let Ok(mut document) = front_matter.parse::<toml_edit::DocumentMut>() else {
+        return front_matter.to_string();
+    };
+    let Some(extends) = document.get_mut("extends").and_then(|item| item.as_value_mut()) else {
+        return front_matter.to_string();
+    };
+    let Some(parent) = extends.as_str().filter(|parent| references(parent, old_name)) else {
+        return front_matter.to_string();
+    };
+
+    let decor = extends.decor().clone();
+    *extends = toml_edit::Value::from(rename_spec(parent, new_name));
+    *extends.decor_mut() = decor;
+    document.to_string()
+}
+
+/// 템플릿 지정자가 주어진 이름을 가리키는지 확인
+fn references(spec: &str, name: &TemplateName) -> bool {
+    TemplateSpec::parse(spec).is_ok_and(|spec| &spec.name == name)
+}
+
+/// 템플릿 지정자의 이름 부분만 변경 (버전 유지)
+fn rename_spec(spec: &str, new_name: &TemplateName) -> String {
+    match spec.split_once('@') {
+        Some((_, version)) => format!("{}@{}", new_name, version),
+        None => new_name.to_string(),
+    }
+}
+
+/// 변수 이름 형식 확인
+fn is_identifier(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-' || c == '.')
+}
+
+/// 조건 변수 값이 참인지 확인
+fn is_truthy(value: Option<&String>) -> bool {
+    match value {
+        Some(value) => !matches!(value.trim(), "" | "false" | "0" | "no"),
+        None => false,
+    }
+}
+
+/// 템플릿 렌더링 (include, extends, 변수, 조건문 처리)
+pub fn render_template(
+    spec: &TemplateSpec,
+    variables: &BTreeMap<String, String>,
+    config: &Config,
+) -> Result<String> {
+    render_localized(spec, None, variables, config)
+}
+
+/// 로케일에 맞는 번역본으로 템플릿 렌더링 (include, extends 대상도 같은 로케일 우선)
+pub fn render_localized(
+    spec: &TemplateSpec,
+    locale: Option<&str>,
+    variables: &BTreeMap<String, String>,
+    config: &Config,
+) -> Result<String> {
+    let store = config.store();
+    Renderer::new(store.as_ref()).locale(locale).render(spec, variables)
+}
+
+/// 템플릿 저장소의 템플릿 렌더러
+///
+/// include, extends 대상도 같은 저장소에서 찾고, 로케일을 지정하면 같은 로케일의
+/// 번역본을 우선 사용
+pub struct Renderer<'a> {
+    store: &'a dyn TemplateStore,
+    locale: Option<&'a str>,
+}
+
+impl<'a> Renderer<'a> {
+    pub fn new(store: &'a dyn TemplateStore) -> Self {
+        Self {
+            store,
+            locale: None,
+        }
+    }
+
+    /// 번역본 로케일 지정
+    pub fn locale(mut self, locale: Option<&'a str>) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// 템플릿 렌더링 (템플릿이 없으면 [`Error::TemplateNotFound`])
+    pub fn render(
+        &self,
+        spec: &TemplateSpec,
+        variables: &BTreeMap<String, String>,
+    ) -> Result<String> {
+        let content = self.store.localized_content(spec, self.locale)?;
+
+        let mut stack = vec![spec.to_string()];
+        self.render_source(&content, variables, &mut stack)
+            .map_err(|source| Error::Render {
+                template: spec.to_string(),
+                source,
+            })
+    }
+
+    /// 토큰 한도 (`max_tokens`가 없으면 extends 대상에서 상속, 가까운 템플릿의 값 우선)
+    pub fn max_tokens(&self, metadata: &TemplateMetadata) -> Result<Option<usize>> {
+        let mut max_tokens = metadata.max_tokens;
+        let mut parent = metadata.extends.clone();
+
+        for _ in 0..MAX_INCLUDE_DEPTH {
+            let Some(reference) = parent.filter(|_| max_tokens.is_none()) else {
+                return Ok(max_tokens);
+            };
+            let spec = TemplateSpec::parse(&reference)
+                .with_context(|| t!("render.invalid_reference", reference))?;
+            let content = self.store.localized_content(&spec, self.locale)?;
+            let (metadata, _) = split_front_matter(&content)?;
+            max_tokens = metadata.max_tokens;
+            parent = metadata.extends;
+        }
+
+        Err(Error::Validation(t!("render.too_deep", MAX_INCLUDE_DEPTH)))
+    }
+
+    /// include, extends로 지정된 템플릿 렌더링
+    fn render_reference(
+        &self,
+        reference: &str,
+        variables: &BTreeMap<String, String>,
+        stack: &mut Vec<String>,
+    ) -> anyhow::Result<String> {
+        let spec = TemplateSpec::parse(reference)
+            .with_context(|| t!("render.invalid_reference", reference))?;
+
+        let name = spec.to_string();
+        if stack.contains(&name) {
+            anyhow::bail!(t!("render.cycle", stack.join(" -> "), name));
+        }
+        if stack.len() >= MAX_INCLUDE_DEPTH {
+            anyhow::bail!(t!("render.too_deep", MAX_INCLUDE_DEPTH));
+        }
+
+        let content = self.store.localized_content(&spec, self.locale)?;
+
+        stack.push(name.clone());
+        let rendered = self
+            .render_source(&content, variables, stack)
+            .with_context(|| t!("render.reference_failed", name))?;
+        stack.pop();
+
+        Ok(rendered)
+    }
+
+    fn render_source(
+        &self,
+        content: &str,
+        variables: &BTreeMap<String, String>,
+        stack: &mut Vec<String>,
+    ) -> anyhow::Result<String> {
+        let (metadata, body) = split_front_matter(content)?;
+
+        // 템플릿 기본값 위에 지정된 변수 적용
+        let mut scope = metadata.variables.clone();
+        scope.extend(variables.iter().map(|(k, v)| (k.clone(), v.clone())));
+
+        let mut output = String::new();
+        if let Some(parent) = &metadata.extends {
+            output.push_str(&self.render_reference(parent, &scope, stack)?);
+        }
+
+        let nodes = parse(body)?;
+        self.render_nodes(&nodes, &scope, stack, &mut output)?;
+
+        Ok(output)
+    }
+
+    fn render_nodes(
+        &self,
+        nodes: &[Node],
+        variables: &BTreeMap<String, String>,
+        stack: &mut Vec<String>,
+        output: &mut String,
+    ) -> anyhow::Result<()> {
+        for node in nodes {
+            match node {
+                Node::Text(text) => output.push_str(text),
+                // 정의되지 않은 변수는 태그 그대로 출력 (검사에서 경고)
+                Node::Variable { name, source } => {
+                    output.push_str(variables.get(name).unwrap_or(source));
+                }
+                Node::Include(name) => {
+                    output.push_str(&self.render_reference(name, variables, stack)?);
+                }
+                Node::If {
+                    condition,
+                    then_branch,
+                    else_branch,
+                } => {
+                    let branch = if is_truthy(variables.get(condition)) {
+                        then_branch
+                    } else {
+                        else_branch
+                    };
+                    self.render_nodes(branch, variables, stack, output)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// `key=value` 형식의 변수 파싱
+pub fn parse_variable(arg: &str) -> Result<(String, String)> {
+    let (key, value) = arg
+        .split_once('=')
+        .ok_or_else(|| {
+            Error::Validation(t!("render.variable_format", arg))
+        })?;
+
+    let key = key.trim();
+    if !is_identifier(key) {
+        return Err(Error::Validation(t!("render.variable_name", key)));
+    }
+
+    Ok((key.to_string(), value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::FsStore;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_split_front_matter() {
+        let content = "+++\ndescription = \"팀 규칙\"\ntags = [\"team\"]\n+++\n# Body\n";
+        let (metadata, body) = split_front_matter(content).unwrap();
+
+        assert_eq!(metadata.description.as_deref(), Some("팀 규칙"));
+        assert_eq!(metadata.tags, vec!["team"]);
+        assert_eq!(body, "# Body\n");
+
+        let (metadata, body) = split_front_matter("# No front matter").unwrap();
+        assert_eq!(metadata, TemplateMetadata::default());
+        assert_eq!(body, "# No front matter");
+
+        assert!(split_front_matter("+++\ndescription = \"x\"\n# Body").is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("{{#if a}}unclosed").is_err());
+        assert!(parse("{{/if}}").is_err());
+        assert!(parse("{{ unclosed").is_err());
+        // 알 수 없는 태그는 오류 대신 그대로
+        assert_eq!(parse("a {{ not valid }}").unwrap(), vec![Node::Text("a {{ not valid }}".into())]);
+        assert_eq!(parse("\\{{ literal }}").unwrap(), vec![Node::Text("{{ literal }}".into())]);
+    }
+
+    #[test]
+    fn test_code_blocks_are_literal() {
+        let body = "# {{ team }}\n```js\nconst s = `{{ x }}`;\n```\n~~~~\n{{#if}}\n```\n~~~~\n{{ team }}";
+        let variables = BTreeMap::from([("team".to_string(), "Core".to_string())]);
+        let temp_dir = tempdir().unwrap();
+        let store = FsStore::new(temp_dir.path());
+        fs::write(temp_dir.path().join("Rules.md"), body).unwrap();
+
+        let rendered = Renderer::new(&store)
+            .render(&TemplateSpec::parse("Rules").unwrap(), &variables)
+            .unwrap();
+        assert_eq!(
+            rendered,
+            "# Core\n```js\nconst s = `{{ x }}`;\n```\n~~~~\n{{#if}}\n```\n~~~~\nCore"
+        );
+
+        // 조건문이 코드 블록을 감쌀 수 있고, 닫히지 않은 코드 블록은 끝까지
+        assert!(parse("{{#if a}}\n```\n{{ x }}\n```\n{{/if}}").is_ok());
+        assert_eq!(parse("```\n{{ x").unwrap(), vec![Node::Text("```\n{{ x".into())]);
+    }
+
+    #[test]
+    fn test_inline_code_and_undefined_are_literal() {
+        let temp_dir = tempdir().unwrap();
+        let store = FsStore::new(temp_dir.path());
+        let body = "Use `{{ name }}` or ``{{#if}} ` {{/if}}`` placeholders, {{ team }} and {{name}}.\n\n`{{ team }}\n\n{{ team }}`";
+        fs::write(temp_dir.path().join("Hb.md"), body).unwrap();
+
+        // 인라인 코드와 정의되지 않은 변수는 원래 내용 그대로, 닫히지 않은 백틱은 일반 문자
+        let variables = BTreeMap::from([("team".to_string(), "Core".to_string())]);
+        let rendered = Renderer::new(&store)
+            .render(&TemplateSpec::parse("Hb").unwrap(), &variables)
+            .unwrap();
+        assert_eq!(
+            rendered,
+            "Use `{{ name }}` or ``{{#if}} ` {{/if}}`` placeholders, Core and {{name}}.\n\n`Core\n\nCore`"
+        );
+    }
+
+    #[test]
+    fn test_replace_template_references() {
+        let content = "+++\nextends = \"Base@1.0\"\n+++\n{{> Base }}{{>Other}}\\{{> Base }}\n```\n{{> Base }}\n```";
+        let replaced = replace_template_references(
+            content,
+            &TemplateName::new("Base").unwrap(),
+            &TemplateName::new("Core").unwrap(),
+        );
+
+        assert_eq!(
+            replaced,
+            "+++\nextends = \"Core@1.0\"\n+++\n{{> Core }}{{>Other}}\\{{> Base }}\n```\n{{> Base }}\n```"
+        );
+    }
+
+    #[test]
+    fn test_replace_extends_keeps_formatting() {
+        let base = TemplateName::new("Base").unwrap();
+        let core = TemplateName::new("Core").unwrap();
+
+        let content = "+++\n# 부모 템플릿\nextends = \"\"\"\nBase@1.0\"\"\"  # 고정 버전\ntags = [\"a\"]\n+++\nbody";
+        assert_eq!(
+            replace_template_references(content, &base, &core),
+            "+++\n# 부모 템플릿\nextends = \"Core@1.0\"  # 고정 버전\ntags = [\"a\"]\n+++\nbody"
+        );
+
+        // 다른 템플릿을 가리키거나 프론트 매터가 올바르지 않으면 그대로
+        for content in ["+++\nextends = \"Based\"\n+++\n", "+++\nextends = [\n+++\n{{> Base }}"] {
+            let replaced = replace_template_references(content, &base, &core);
+            assert_eq!(replaced.split("+++").nth(1), content.split("+++").nth(1));
+        }
+    }
+
+    #[test]
+    fn test_render_template() {
+        let temp_dir = tempdir().unwrap();
+        let config = Config::new(temp_dir.path().to_path_buf());
+
+        fs::write(temp_dir.path().join("Footer.md"), "-- {{ team }} --").unwrap();
+        fs::write(
+            temp_dir.path().join("Rules.md"),
+            "+++\n[variables]\nteam = \"Core\"\n+++\n# {{ team }}\n{{#if strict}}strict{{else}}relaxed{{/if}}\n{{> Footer }}",
+        )
+        .unwrap();
+
+        let rules = TemplateSpec::parse("Rules").unwrap();
+        let rendered = render_template(&rules, &BTreeMap::new(), &config).unwrap();
+        assert_eq!(rendered, "# Core\nrelaxed\n-- Core --");
+
+        let variables = BTreeMap::from([
+            ("team".to_string(), "Web".to_string()),
+            ("strict".to_string(), "true".to_string()),
+        ]);
+        let rendered = render_template(&rules, &variables, &config).unwrap();
+        assert_eq!(rendered, "# Web\nstrict\n-- Web --");
+
+        // 순환 참조 감지
+        fs::write(temp_dir.path().join("Loop.md"), "{{> Loop }}").unwrap();
+        assert!(render_template(&TemplateSpec::parse("Loop").unwrap(), &BTreeMap::new(), &config).is_err());
+    }
+
+    #[test]
+    fn test_max_tokens_follows_extends() {
+        let temp_dir = tempdir().unwrap();
+        let store = FsStore::new(temp_dir.path());
+        let renderer = Renderer::new(&store);
+        fs::write(temp_dir.path().join("Base.md"), "+++\nmax_tokens = 100\n+++\nbase").unwrap();
+        fs::write(temp_dir.path().join("Middle.md"), "+++\nextends = \"Base\"\n+++\n").unwrap();
+
+        let metadata = |content: &str| split_front_matter(content).unwrap().0;
+        let child = metadata("+++\nextends = \"Middle\"\n+++\n");
+        assert_eq!(renderer.max_tokens(&child).unwrap(), Some(100));
+
+        // 자식 템플릿의 값이 우선
+        let child = metadata("+++\nextends = \"Middle\"\nmax_tokens = 50\n+++\n");
+        assert_eq!(renderer.max_tokens(&child).unwrap(), Some(50));
+        assert_eq!(renderer.max_tokens(&TemplateMetadata::default()).unwrap(), None);
+
+        let broken = metadata("+++\nextends = \"Missing\"\n+++\n");
+        assert!(renderer.max_tokens(&broken).is_err());
+    }
+
+    #[test]
+    fn test_renderer_errors() {
+        let temp_dir = tempdir().unwrap();
+        let store = FsStore::new(temp_dir.path());
+        let renderer = Renderer::new(&store);
+        fs::write(temp_dir.path().join("Broken.md"), "{{#if missing}}").unwrap();
+
+        let missing = TemplateSpec::parse("Missing").unwrap();
+        let result = renderer.render(&missing, &BTreeMap::new());
+        assert!(matches!(result, Err(Error::TemplateNotFound(_))));
+
+        let broken = TemplateSpec::parse("Broken").unwrap();
+        let result = renderer.render(&broken, &BTreeMap::new());
+        assert!(matches!(result, Err(Error::Render { ref template, .. }) if template == "Broken"));
+    }
+}
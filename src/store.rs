@@ -0,0 +1,653 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::backup;
+use crate::embedded;
+use crate::error::{Error, Result};
+use crate::i18n::t;
+use crate::name::{self, TemplateSpec};
+use crate::template::{self, Template};
+
+/// 템플릿 저장소 (템플릿 목록 조회, 읽기, 쓰기)
+///
+/// 버전 선택, 번역본 선택 등 조회 규칙은 기본 구현을 공유하므로 저장소는
+/// 템플릿 목록과 내용 읽기만 구현하면 됨. 쓰기를 구현하지 않은 저장소는 읽기 전용
+pub trait TemplateStore {
+    /// 저장된 모든 템플릿 (번역본 포함, 이름, 버전, 로케일순 정렬)
+    fn templates(&self) -> Result<Vec<Template>>;
+
+    /// 템플릿 내용 읽기
+    fn read(&self, template: &Template) -> Result<String>;
+
+    /// 새 템플릿 작성 (같은 이름, 버전, 로케일의 템플릿이 있으면 실패)
+    fn create(&self, spec: &TemplateSpec, locale: Option<&str>, content: &str) -> Result<Template> {
+        let _ = (spec, locale, content);
+        Err(Error::ReadOnly)
+    }
+
+    /// 템플릿 작성 (이미 있으면 덮어쓰기)
+    fn write(&self, spec: &TemplateSpec, locale: Option<&str>, content: &str) -> Result<Template> {
+        let _ = (spec, locale, content);
+        Err(Error::ReadOnly)
+    }
+
+    /// 템플릿 삭제
+    fn remove(&self, template: &Template) -> Result<()> {
+        let _ = template;
+        Err(Error::ReadOnly)
+    }
+
+    /// 같은 이름, 버전, 로케일의 템플릿 찾기
+    fn find(&self, spec: &TemplateSpec, locale: Option<&str>) -> Result<Option<Template>> {
+        Ok(self
+            .templates()?
+            .into_iter()
+            .find(|t| is_entry(t, spec, locale)))
+    }
+
+    /// 이름별 최신 버전 목록 (번역본 제외)
+    fn list(&self) -> Result<Vec<Template>> {
+        let mut templates: Vec<Template> = Vec::new();
+
+        for template in self.versions()? {
+            // 정렬되어 있으므로 같은 이름의 마지막 항목이 최신 버전
+            match templates.last_mut() {
+                Some(last) if last.name == template.name => *last = template,
+                _ => templates.push(template),
+            }
+        }
+
+        Ok(templates)
+    }
+
+    /// 모든 버전 목록 (번역본 제외)
+    fn versions(&self) -> Result<Vec<Template>> {
+        Ok(self
+            .templates()?
+            .into_iter()
+            .filter(|t| t.locale.is_none())
+            .collect())
+    }
+
+    /// 템플릿과 같은 이름, 버전의 번역본 로케일 목록
+    fn locales(&self, template: &Template) -> Result<Vec<String>> {
+        Ok(self
+            .templates()?
+            .into_iter()
+            .filter(|t| t.name == template.name && t.version == template.version)
+            .filter_map(|t| t.locale)
+            .collect())
+    }
+
+    /// 템플릿 지정자에 해당하는 템플릿 찾기
+    ///
+    /// 버전을 지정하지 않으면(`name`) 최신 버전이고, 버전 없는 파일은 가장 낮은 버전으로
+    /// 취급. 버전 없는 파일만 가리키려면 `name@` 사용
+    fn resolve(&self, spec: &TemplateSpec) -> Result<Template> {
+        self.templates()?
+            .into_iter()
+            .rfind(|t| {
+                t.locale.is_none()
+                    && t.name == spec.name
+                    && spec.matches_version(t.version.as_deref())
+            })
+            .ok_or_else(|| Error::TemplateNotFound(spec.clone()))
+    }
+
+    /// 로케일에 맞는 번역본 찾기 (번역본이 없으면 기본 파일)
+    ///
+    /// 버전은 기본 파일 기준으로 정하고, 같은 버전의 번역본 중 로케일이 완전히 일치하는 것,
+    /// 언어만 일치하는 것 순으로 선택
+    fn resolve_localized(&self, spec: &TemplateSpec, locale: Option<&str>) -> Result<Template> {
+        let base = self.resolve(spec)?;
+        let Some(locale) = locale else {
+            return Ok(base);
+        };
+
+        let translation = self
+            .templates()?
+            .into_iter()
+            .filter(|t| t.name == base.name && t.version == base.version)
+            .filter_map(|t| {
+                let score = template::locale_score(t.locale.as_deref()?, locale);
+                (score > 0).then_some((score, t))
+            })
+            .max_by_key(|(score, _)| *score)
+            .map(|(_, t)| t);
+
+        Ok(translation.unwrap_or(base))
+    }
+
+    /// 템플릿 내용 가져오기 (버전을 지정하지 않으면 최신 버전)
+    fn content(&self, spec: &TemplateSpec) -> Result<String> {
+        self.read(&self.resolve(spec)?)
+    }
+
+    /// 로케일에 맞는 번역본 내용 가져오기 (번역본이 없으면 기본 파일)
+    fn localized_content(&self, spec: &TemplateSpec, locale: Option<&str>) -> Result<String> {
+        self.read(&self.resolve_localized(spec, locale)?)
+    }
+}
+
+/// 디렉토리에 `이름[@버전][.로케일].md` 파일로 저장된 템플릿 저장소
+#[derive(Debug, Clone)]
+pub struct FsStore {
+    root: PathBuf,
+}
+
+/// 템플릿 목록에서 제외한 파일
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedFile {
+    pub path: PathBuf,
+    pub reason: String,
+}
+
+impl FsStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// 템플릿 디렉토리
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// 파일명을 템플릿 이름으로 쓸 수 없어 목록에서 제외한 `.md` 파일
+    pub fn skipped(&self) -> Result<Vec<SkippedFile>> {
+        Ok(self.scan()?.1)
+    }
+
+    /// 디렉토리 내 `.md` 파일을 템플릿과 제외한 파일로 분류
+    fn scan(&self) -> Result<(Vec<Template>, Vec<SkippedFile>)> {
+        let mut templates = Vec::new();
+        let mut skipped = Vec::new();
+
+        // 템플릿 디렉토리 확인
+        if !self.root.exists() {
+            return Ok((templates, skipped));
+        }
+
+        // 디렉토리 내 .md 파일 검색
+        let io_error = |source| Error::Io {
+            path: self.root.clone(),
+            source,
+        };
+        for entry in fs::read_dir(&self.root).map_err(io_error)? {
+            let path = entry.map_err(io_error)?.path();
+
+            if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("md") {
+                // 템플릿 이름으로 사용할 수 없는 파일명은 이유와 함께 따로 기록
+                let parsed = match path.file_stem().and_then(|s| s.to_str()) {
+                    Some(stem) => name::parse_localized(stem).map_err(|e| e.to_string()),
+                    None => Err(t!("store.non_utf8_name").to_string()),
+                };
+                let (spec, locale) = match parsed {
+                    Ok(parsed) => parsed,
+                    Err(reason) => {
+                        skipped.push(SkippedFile { path, reason });
+                        continue;
+                    }
+                };
+
+                templates.push(Template {
+                    name: spec.name,
+                    version: spec.version,
+                    locale,
+                    path,
+                });
+            }
+        }
+
+        sort_templates(&mut templates);
+        skipped.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok((templates, skipped))
+    }
+}
+
+impl TemplateStore for FsStore {
+    fn templates(&self) -> Result<Vec<Template>> {
+        Ok(self.scan()?.0)
+    }
+
+    fn read(&self, template: &Template) -> Result<String> {
+        fs::read_to_string(&template.path).map_err(|source| Error::Io {
+            path: template.path.clone(),
+            source,
+        })
+    }
+
+    fn create(&self, spec: &TemplateSpec, locale: Option<&str>, content: &str) -> Result<Template> {
+        let path = self.prepare(spec, locale)?;
+
+        // 임시 파일에 모두 쓴 뒤 링크하므로 중단되어도 잘린 파일이 남지 않고,
+        // 중복 확인과 생성을 한 번에 처리 (기존 파일 덮어쓰기 방지)
+        match backup::create_atomic(&path, content) {
+            Ok(()) => Ok(entry(spec, locale, path)),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                Err(Error::Conflict(t!("template.exists", entry_name(spec, locale))))
+            }
+            Err(source) => Err(Error::Io { path, source }),
+        }
+    }
+
+    fn write(&self, spec: &TemplateSpec, locale: Option<&str>, content: &str) -> Result<Template> {
+        let path = self.prepare(spec, locale)?;
+        // 덮어쓰는 도중 실패해도 기존 내용이 남도록 원자적으로 작성
+        backup::write_atomic(&path, content)?;
+
+        Ok(entry(spec, locale, path))
+    }
+
+    fn remove(&self, template: &Template) -> Result<()> {
+        fs::remove_file(&template.path).map_err(|source| Error::Io {
+            path: template.path.clone(),
+            source,
+        })
+    }
+}
+
+impl FsStore {
+    /// 템플릿 디렉토리를 만들고 작성할 파일 경로 반환
+    fn prepare(&self, spec: &TemplateSpec, locale: Option<&str>) -> Result<PathBuf> {
+        fs::create_dir_all(&self.root).map_err(|source| Error::Io {
+            path: self.root.clone(),
+            source,
+        })?;
+
+        Ok(self.root.join(template::file_name_for(spec, locale)))
+    }
+}
+
+/// 메모리에 보관하는 템플릿 저장소 (테스트, 임시 템플릿용)
+///
+/// 템플릿 경로는 `이름[@버전][.로케일].md` 파일명
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    files: RefCell<BTreeMap<String, String>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 파일명과 내용 목록으로 저장소 생성 (템플릿 이름으로 쓸 수 없는 파일명은 무시)
+    pub fn with_files<'a>(files: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+        let files = files
+            .into_iter()
+            .map(|(file_name, content)| (file_name.to_string(), content.to_string()))
+            .collect();
+        Self {
+            files: RefCell::new(files),
+        }
+    }
+}
+
+impl TemplateStore for MemoryStore {
+    fn templates(&self) -> Result<Vec<Template>> {
+        Ok(entries(self.files.borrow().keys().map(String::as_str)))
+    }
+
+    fn read(&self, template: &Template) -> Result<String> {
+        self.files
+            .borrow()
+            .get(&file_key(template))
+            .cloned()
+            .ok_or_else(|| Error::TemplateNotFound(template.spec()))
+    }
+
+    fn create(&self, spec: &TemplateSpec, locale: Option<&str>, content: &str) -> Result<Template> {
+        let file_name = template::file_name_for(spec, locale);
+        if self.files.borrow().contains_key(&file_name) {
+            return Err(Error::Conflict(t!("template.exists", entry_name(spec, locale))));
+        }
+        self.write(spec, locale, content)
+    }
+
+    fn write(&self, spec: &TemplateSpec, locale: Option<&str>, content: &str) -> Result<Template> {
+        let file_name = template::file_name_for(spec, locale);
+        self.files
+            .borrow_mut()
+            .insert(file_name.clone(), content.to_string());
+
+        Ok(entry(spec, locale, PathBuf::from(file_name)))
+    }
+
+    fn remove(&self, template: &Template) -> Result<()> {
+        self.files
+            .borrow_mut()
+            .remove(&file_key(template))
+            .map(|_| ())
+            .ok_or_else(|| Error::TemplateNotFound(template.spec()))
+    }
+}
+
+/// 바이너리에 내장된 템플릿 저장소 (읽기 전용)
+///
+/// 템플릿 경로는 `이름[.로케일].md` 파일명
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EmbeddedStore;
+
+impl EmbeddedStore {
+    /// 내장 템플릿 파일 목록 (파일명, 내용)
+    fn files() -> impl Iterator<Item = (String, &'static str)> {
+        let templates = embedded::builtin_names().into_iter().filter_map(|name| {
+            embedded::find_builtin(name).map(|(name, content)| (format!("{}.md", name), content))
+        });
+        let translations = embedded::get_embedded_translations()
+            .into_iter()
+            .map(|(name, locale, content)| (format!("{}.{}.md", name, locale), content));
+
+        templates.chain(translations)
+    }
+}
+
+impl TemplateStore for EmbeddedStore {
+    fn templates(&self) -> Result<Vec<Template>> {
+        let files: Vec<String> = Self::files().map(|(file_name, _)| file_name).collect();
+        Ok(entries(files.iter().map(String::as_str)))
+    }
+
+    fn read(&self, template: &Template) -> Result<String> {
+        let key = file_key(template);
+        Self::files()
+            .find(|(file_name, _)| *file_name == key)
+            .map(|(_, content)| content.to_string())
+            .ok_or_else(|| Error::TemplateNotFound(template.spec()))
+    }
+}
+
+/// 여러 저장소를 겹친 저장소
+///
+/// 앞쪽 저장소가 우선하며 같은 이름, 버전, 로케일의 템플릿은 앞쪽 저장소의 것만 보임.
+/// 새로 쓰는 템플릿은 가장 앞쪽 저장소에 작성되고, 삭제는 템플릿이 있는 저장소에서 처리
+#[derive(Default)]
+pub struct LayeredStore {
+    layers: Vec<Box<dyn TemplateStore>>,
+}
+
+impl LayeredStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 가장 낮은 우선순위로 저장소 추가
+    pub fn with(mut self, store: impl TemplateStore + 'static) -> Self {
+        self.layers.push(Box::new(store));
+        self
+    }
+
+    /// 템플릿을 보이게 하는 (가장 앞쪽) 저장소
+    fn owner(&self, template: &Template) -> Result<&dyn TemplateStore> {
+        let locale = template.locale.as_deref();
+        for layer in &self.layers {
+            if layer.find(&template.spec(), locale)?.is_some() {
+                return Ok(layer.as_ref());
+            }
+        }
+        Err(Error::TemplateNotFound(template.spec()))
+    }
+
+    /// 쓰기를 받는 가장 앞쪽 저장소
+    fn top(&self) -> Result<&dyn TemplateStore> {
+        self.layers
+            .first()
+            .map(|layer| layer.as_ref())
+            .ok_or(Error::ReadOnly)
+    }
+}
+
+impl TemplateStore for LayeredStore {
+    fn templates(&self) -> Result<Vec<Template>> {
+        let mut templates: Vec<Template> = Vec::new();
+        for layer in &self.layers {
+            for template in layer.templates()? {
+                let shadowed = templates.iter().any(|t| {
+                    is_entry(t, &template.spec(), template.locale.as_deref())
+                });
+                if !shadowed {
+                    templates.push(template);
+                }
+            }
+        }
+
+        sort_templates(&mut templates);
+        Ok(templates)
+    }
+
+    fn read(&self, template: &Template) -> Result<String> {
+        self.owner(template)?.read(template)
+    }
+
+    fn create(&self, spec: &TemplateSpec, locale: Option<&str>, content: &str) -> Result<Template> {
+        // 아래쪽 저장소의 템플릿을 가리게 되는 경우도 충돌로 취급
+        if self.find(spec, locale)?.is_some() {
+            return Err(Error::Conflict(t!("template.exists", entry_name(spec, locale))));
+        }
+        self.top()?.create(spec, locale, content)
+    }
+
+    fn write(&self, spec: &TemplateSpec, locale: Option<&str>, content: &str) -> Result<Template> {
+        self.top()?.write(spec, locale, content)
+    }
+
+    fn remove(&self, template: &Template) -> Result<()> {
+        self.owner(template)?.remove(template)
+    }
+}
+
+/// `Config::store()`처럼 상자에 담긴 저장소도 다른 저장소와 겹칠 수 있도록 위임
+impl TemplateStore for Box<dyn TemplateStore> {
+    fn templates(&self) -> Result<Vec<Template>> {
+        (**self).templates()
+    }
+
+    fn read(&self, template: &Template) -> Result<String> {
+        (**self).read(template)
+    }
+
+    fn create(&self, spec: &TemplateSpec, locale: Option<&str>, content: &str) -> Result<Template> {
+        (**self).create(spec, locale, content)
+    }
+
+    fn write(&self, spec: &TemplateSpec, locale: Option<&str>, content: &str) -> Result<Template> {
+        (**self).write(spec, locale, content)
+    }
+
+    fn remove(&self, template: &Template) -> Result<()> {
+        (**self).remove(template)
+    }
+}
+
+/// 템플릿이 지정자, 로케일과 같은 항목인지 확인
+fn is_entry(template: &Template, spec: &TemplateSpec, locale: Option<&str>) -> bool {
+    template.name == spec.name
+        && template.version == spec.version
+        && template.locale.as_deref() == locale
+}
+
+/// 메시지용 항목 이름 (`이름[@버전][.로케일]`)
+fn entry_name(spec: &TemplateSpec, locale: Option<&str>) -> String {
+    match locale {
+        Some(locale) => format!("{}.{}", spec.file_stem(), locale),
+        None => spec.file_stem(),
+    }
+}
+
+/// 지정자, 로케일, 경로로 템플릿 항목 생성
+fn entry(spec: &TemplateSpec, locale: Option<&str>, path: PathBuf) -> Template {
+    Template {
+        name: spec.name.clone(),
+        version: spec.version.clone(),
+        locale: locale.map(str::to_string),
+        path,
+    }
+}
+
+/// 파일명 기반 저장소에서 템플릿을 찾는 키
+fn file_key(template: &Template) -> String {
+    template::file_name_for(&template.spec(), template.locale.as_deref())
+}
+
+/// 파일명 목록을 정렬된 템플릿 목록으로 변환 (템플릿 이름으로 쓸 수 없는 파일명은 무시)
+fn entries<'a>(file_names: impl Iterator<Item = &'a str>) -> Vec<Template> {
+    let mut templates: Vec<Template> = file_names
+        .filter_map(|file_name| {
+            let (spec, locale) = name::parse_localized(file_name.strip_suffix(".md")?).ok()?;
+            Some(entry(&spec, locale.as_deref(), PathBuf::from(file_name)))
+        })
+        .collect();
+
+    sort_templates(&mut templates);
+    templates
+}
+
+/// 이름, 버전, 로케일순 정렬 (기본 파일이 번역본보다 앞)
+fn sort_templates(templates: &mut [Template]) {
+    templates.sort_by(|a, b| {
+        a.name
+            .cmp(&b.name)
+            .then_with(|| template::compare_versions(a.version.as_deref(), b.version.as_deref()))
+            .then_with(|| a.locale.cmp(&b.locale))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn spec(s: &str) -> TemplateSpec {
+        TemplateSpec::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_fs_store() {
+        let dir = tempdir().unwrap();
+        let store = FsStore::new(dir.path());
+        assert!(store.list().unwrap().is_empty());
+
+        fs::write(dir.path().join("Rules@1.0.md"), "1.0").unwrap();
+        fs::write(dir.path().join("Rules@1.0.en.md"), "1.0 en").unwrap();
+        fs::write(dir.path().join("Rules@1.10.md"), "1.10").unwrap();
+        fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+        fs::write(dir.path().join("bad:name.md"), "ignored").unwrap();
+
+        fs::write(dir.path().join("Backend.dev.md"), "dev").unwrap();
+
+        assert_eq!(store.templates().unwrap().len(), 4);
+        assert_eq!(store.versions().unwrap().len(), 3);
+        assert_eq!(store.list().unwrap()[1].id(), "Rules@1.10");
+
+        // 로케일 형식이 아닌 접미사는 번역본이 아니라 이름의 일부
+        assert_eq!(store.list().unwrap()[0].id(), "Backend.dev");
+
+        // 템플릿 이름으로 쓸 수 없는 파일명은 이유와 함께 제외
+        let skipped = store.skipped().unwrap();
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].path, dir.path().join("bad:name.md"));
+        assert!(skipped[0].reason.contains(':'));
+
+        assert_eq!(store.content(&spec("Rules")).unwrap(), "1.10");
+        let content = store.localized_content(&spec("Rules@1.0"), Some("en")).unwrap();
+        assert_eq!(content, "1.0 en");
+
+        assert!(matches!(
+            store.resolve(&spec("Missing")),
+            Err(Error::TemplateNotFound(_))
+        ));
+
+        // 버전만 있으면 버전 없는 파일은 찾을 수 없음
+        assert!(matches!(
+            store.resolve(&spec("Rules@")),
+            Err(Error::TemplateNotFound(_))
+        ));
+
+        let created = store.create(&spec("New"), Some("en"), "new").unwrap();
+        assert_eq!(created.path, dir.path().join("New.en.md"));
+        assert!(matches!(
+            store.create(&spec("New"), Some("en"), "again"),
+            Err(Error::Conflict(_))
+        ));
+        store.remove(&created).unwrap();
+        assert!(!created.path.exists());
+    }
+
+    #[test]
+    fn test_memory_store() {
+        let store = MemoryStore::with_files([("Rules.md", "# Rules"), ("bad:name.md", "ignored")]);
+        assert_eq!(store.list().unwrap().len(), 1);
+
+        let template = store.write(&spec("Rules@2.0"), None, "# v2").unwrap();
+        assert_eq!(template.path, PathBuf::from("Rules@2.0.md"));
+        assert_eq!(store.content(&spec("Rules")).unwrap(), "# v2");
+        assert!(matches!(
+            store.create(&spec("Rules"), None, ""),
+            Err(Error::Conflict(_))
+        ));
+
+        store.remove(&template).unwrap();
+        assert_eq!(store.content(&spec("Rules")).unwrap(), "# Rules");
+    }
+
+    #[test]
+    fn test_embedded_store() {
+        let store = EmbeddedStore;
+        let names: Vec<String> = store.list().unwrap().iter().map(Template::id).collect();
+        assert_eq!(names.len(), embedded::builtin_names().len());
+
+        let content = store
+            .localized_content(&spec(embedded::DEFAULT_TEMPLATE_NAME), Some("en"))
+            .unwrap();
+        assert_eq!(content, embedded::PROGRAMMING_TEAM_EN);
+        assert!(matches!(
+            store.write(&spec("Rules"), None, ""),
+            Err(Error::ReadOnly)
+        ));
+    }
+
+    #[test]
+    fn test_layered_store() {
+        let top = tempdir().unwrap();
+        let store = LayeredStore::new()
+            .with(FsStore::new(top.path()))
+            .with(EmbeddedStore);
+        let default = spec(embedded::DEFAULT_TEMPLATE_NAME);
+        assert_eq!(store.content(&default).unwrap(), embedded::PROGRAMMING_TEAM);
+
+        // 아래쪽 템플릿과 겹치는 새 템플릿은 충돌, 덮어쓰면 위쪽 저장소에 작성되어 가려짐
+        assert!(matches!(
+            store.create(&default, None, ""),
+            Err(Error::Conflict(_))
+        ));
+        store.write(&default, None, "# Custom").unwrap();
+        assert_eq!(store.content(&default).unwrap(), "# Custom");
+        assert_eq!(store.list().unwrap().len(), embedded::builtin_names().len());
+
+        // 새 버전을 설치해도 버전 없는 파일은 `이름@`으로 지정
+        let versioned = spec(&format!("{}@1.2", embedded::DEFAULT_TEMPLATE_NAME));
+        let unversioned = spec(&format!("{}@", embedded::DEFAULT_TEMPLATE_NAME));
+        let v12 = store.write(&versioned, None, "# 1.2").unwrap();
+        assert_eq!(store.content(&default).unwrap(), "# 1.2");
+        assert_eq!(store.content(&unversioned).unwrap(), "# Custom");
+        store.remove(&v12).unwrap();
+
+        // 위쪽 템플릿을 지우면 내장 템플릿이 다시 보이고, 내장 템플릿은 지울 수 없음
+        let custom = store.resolve(&default).unwrap();
+        store.remove(&custom).unwrap();
+        let builtin = store.resolve(&default).unwrap();
+        assert_eq!(store.read(&builtin).unwrap(), embedded::PROGRAMMING_TEAM);
+        assert!(matches!(store.remove(&builtin), Err(Error::ReadOnly)));
+
+        // 상자에 담긴 저장소 위에 메모리 저장소를 겹쳐 저장하지 않은 템플릿 사용
+        let boxed: Box<dyn TemplateStore> = Box::new(store);
+        let overlay = LayeredStore::new().with(MemoryStore::new()).with(boxed);
+        overlay.create(&spec("Draft"), None, "{{> Programming-Team }}").unwrap();
+        assert_eq!(overlay.content(&spec("Draft")).unwrap(), "{{> Programming-Team }}");
+        assert!(overlay.resolve(&default).is_ok());
+        assert!(fs::read_dir(top.path()).unwrap().next().is_none());
+    }
+}
@@ -1,140 +1,754 @@
-use anyhow::{Context, Result};
-use std::fs;
+use anyhow::Context;
+use std::cmp::Ordering;
+use std::fs::{self, File};
+use std::io::Read;
 use std::path::{Path, PathBuf};
 
 use crate::config::Config;
 use crate::embedded;
+use crate::error::{Error, Result};
+use crate::i18n::t;
+use crate::lint::{self, LintIssue, Severity};
+use crate::name::{TemplateName, TemplateSpec};
+use crate::render;
+use crate::store::{EmbeddedStore, SkippedFile, TemplateStore};
 
 /// 템플릿 정보
 #[derive(Debug, Clone)]
 pub struct Template {
-    pub name: String,
-    #[allow(dead_code)] // 향후 기능 확장을 위해 유지
+    pub name: TemplateName,
+    /// 템플릿 버전 (`name@version.md` 형식으로 저장된 경우)
+    pub version: Option<String>,
+    /// 번역본 로케일 (`name.<locale>.md` 형식으로 저장된 경우)
+    pub locale: Option<String>,
+    /// 저장소 안의 위치 (디렉토리 저장소는 파일 경로, 그 밖의 저장소는 파일명)
     pub path: PathBuf,
 }
 
-/// 템플릿 목록 조회
-pub fn list_templates(config: &Config) -> Result<Vec<Template>> {
-    let mut templates = Vec::new();
+impl Template {
+    /// 템플릿 지정자
+    pub fn spec(&self) -> TemplateSpec {
+        TemplateSpec::exact(self.name.clone(), self.version.clone())
+    }
+
+    /// `name` 또는 `name@version` 형식의 식별자
+    pub fn id(&self) -> String {
+        self.spec().file_stem()
+    }
+}
 
-    // 템플릿 디렉토리 확인
-    if !config.template_path.exists() {
-        return Ok(templates);
+/// 템플릿 지정자와 로케일에 해당하는 파일명
+pub(crate) fn file_name_for(spec: &TemplateSpec, locale: Option<&str>) -> String {
+    match locale {
+        Some(locale) => format!("{}.{}.md", spec.file_stem(), locale),
+        None => spec.file_name(),
     }
+}
 
-    // 디렉토리 내 .md 파일 검색
-    let entries = fs::read_dir(&config.template_path)
-        .with_context(|| format!("템플릿 디렉토리를 읽을 수 없습니다: {:?}", config.template_path))?;
+/// 요청한 로케일과 번역본 로케일의 일치 정도 (0: 불일치, 1: 언어만 일치, 2: 완전 일치)
+pub fn locale_score(available: &str, requested: &str) -> u8 {
+    let normalize = |locale: &str| locale.replace('_', "-").to_ascii_lowercase();
+    let (available, requested) = (normalize(available), normalize(requested));
+    let language = |locale: &str| locale.split('-').next().unwrap_or_default().to_string();
 
-    for entry in entries {
-        let entry = entry?;
-        let path = entry.path();
+    if available == requested {
+        2
+    } else if language(&available) == language(&requested) {
+        1
+    } else {
+        0
+    }
+}
 
-        if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("md") {
-            if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
-                templates.push(Template {
-                    name: stem.to_string(),
-                    path: path.clone(),
-                });
-            }
+/// 버전 비교 (버전 없음이 가장 낮음)
+///
+/// 점으로 구분한 구간을 앞에서부터 비교하고, 구간이 모자란 쪽은 `0`으로 채움
+/// (`1.2` == `1.2.0`). 각 구간은 앞쪽 숫자와 나머지 접미사로 나눠 다음 순서로 비교:
+/// 1. 숫자 크기 (`9` < `10`, 숫자로 시작하지 않는 구간이 가장 낮음: `beta` < `0`)
+/// 2. 숫자가 같으면 접미사가 있는 쪽이 낮음 (`9a` < `9`, `0-rc1` < `0`)
+/// 3. 접미사끼리는 문자열 순서 (`9a` < `9b`)
+pub fn compare_versions(a: Option<&str>, b: Option<&str>) -> Ordering {
+    let (a, b) = match (a, b) {
+        (None, None) => return Ordering::Equal,
+        (None, Some(_)) => return Ordering::Less,
+        (Some(_), None) => return Ordering::Greater,
+        (Some(a), Some(b)) => (a, b),
+    };
+
+    // (앞쪽 숫자의 자릿수와 값, 접미사 없음, 접미사)
+    fn key(segment: &str) -> (Option<(usize, &str)>, bool, &str) {
+        let split = segment
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(segment.len());
+        let (digits, suffix) = segment.split_at(split);
+        let number = (!digits.is_empty()).then(|| {
+            let digits = digits.trim_start_matches('0');
+            (digits.len(), digits)
+        });
+        (number, suffix.is_empty(), suffix)
+    }
+
+    let a_parts: Vec<&str> = a.split('.').collect();
+    let b_parts: Vec<&str> = b.split('.').collect();
+
+    for i in 0..a_parts.len().max(b_parts.len()) {
+        let x = a_parts.get(i).copied().unwrap_or("0");
+        let y = b_parts.get(i).copied().unwrap_or("0");
+
+        let ordering = key(x).cmp(&key(y));
+        if ordering != Ordering::Equal {
+            return ordering;
         }
     }
 
-    // 이름순 정렬
-    templates.sort_by(|a, b| a.name.cmp(&b.name));
+    Ordering::Equal
+}
 
-    Ok(templates)
+/// 템플릿 디렉토리의 모든 템플릿 파일 조회 (이름, 버전, 로케일순 정렬)
+fn scan_templates(config: &Config) -> Result<Vec<Template>> {
+    config.store().templates()
 }
 
-/// 템플릿 설치
-pub fn install_template(source: &Path, name: &str, config: &Config) -> Result<()> {
-    // 파일 존재 확인
-    if !source.exists() {
-        anyhow::bail!("파일을 찾을 수 없습니다: {:?}", source);
+/// 템플릿 목록 조회 (이름별 최신 버전만, 번역본 제외)
+pub fn list_templates(config: &Config) -> Result<Vec<Template>> {
+    config.store().list()
+}
+
+/// 설치된 모든 버전의 템플릿 목록 조회 (번역본 제외)
+pub fn list_template_versions(config: &Config) -> Result<Vec<Template>> {
+    config.store().versions()
+}
+
+/// 템플릿과 같은 이름, 버전의 번역본 로케일 목록
+pub fn list_locales(template: &Template, config: &Config) -> Result<Vec<String>> {
+    config.store().locales(template)
+}
+
+/// 템플릿 지정자에 해당하는 템플릿 찾기 (버전을 지정하지 않으면 최신 버전)
+pub fn resolve_template(spec: &TemplateSpec, config: &Config) -> Result<Template> {
+    config.store().resolve(spec)
+}
+
+/// 로케일에 맞는 번역본 찾기 (번역본이 없으면 기본 파일)
+pub fn resolve_localized(
+    spec: &TemplateSpec,
+    locale: Option<&str>,
+    config: &Config,
+) -> Result<Template> {
+    config.store().resolve_localized(spec, locale)
+}
+
+/// 템플릿 경로에 설치된 템플릿 파일 경로 (내장 템플릿이면 None)
+pub fn installed_path(template: &Template, config: &Config) -> Result<Option<PathBuf>> {
+    if config.builtin_only {
+        return Ok(None);
     }
 
+    Ok(config
+        .installed_store()
+        .find(&template.spec(), template.locale.as_deref())?
+        .map(|installed| installed.path))
+}
+
+/// 템플릿 경로에서 템플릿으로 읽지 않은 파일 (잘못된 파일명, 기본 파일이 없는 번역본)
+pub fn skipped_files(config: &Config) -> Result<Vec<SkippedFile>> {
+    if config.builtin_only {
+        return Ok(Vec::new());
+    }
+
+    let installed = config.installed_store();
+    let mut skipped = installed.skipped()?;
+
+    // 기본 파일이 없는 번역본은 목록에 나오지 않고 어떤 명령에서도 선택되지 않음
+    let bases = config.store().versions()?;
+    for translation in installed.templates()?.into_iter().filter(|t| t.locale.is_some()) {
+        let has_base = bases
+            .iter()
+            .any(|t| t.name == translation.name && t.version == translation.version);
+        if !has_base {
+            skipped.push(SkippedFile {
+                reason: t!("store.orphan_translation", translation.spec().file_name()),
+                path: translation.path,
+            });
+        }
+    }
+
+    skipped.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(skipped)
+}
+
+/// 템플릿 이름이 (어떤 버전이든) 설치되어 있는지 확인
+pub fn template_exists(name: &TemplateName, config: &Config) -> Result<bool> {
+    Ok(scan_templates(config)?.iter().any(|t| &t.name == name))
+}
+
+/// 설치 가능한 템플릿 파일 최대 크기 (10MB)
+const MAX_TEMPLATE_SIZE: u64 = 10 * 1024 * 1024;
+
+/// 템플릿 설치 (검사 경고 목록 반환)
+///
+/// 설치 전에 템플릿을 검사하고 오류가 있으면 설치하지 않음. 아직 설치되지 않은
+/// include, extends 대상은 경고로만 표시
+pub fn install_template(
+    source: &Path,
+    spec: &TemplateSpec,
+    config: &Config,
+) -> Result<Vec<LintIssue>> {
+    install_localized_template(source, spec, None, config)
+}
+
+/// 템플릿 또는 번역본 설치 (`locale`을 지정하면 `이름[@버전].<로케일>.md`로 저장)
+pub fn install_localized_template(
+    source: &Path,
+    spec: &TemplateSpec,
+    locale: Option<&str>,
+    config: &Config,
+) -> Result<Vec<LintIssue>> {
     // .md 확장자 확인
     if source.extension().and_then(|s| s.to_str()) != Some("md") {
-        anyhow::bail!("마크다운 파일(.md)만 설치할 수 있습니다");
+        return Err(Error::Validation(t!("template.markdown_only").to_string()));
+    }
+
+    // 원본 파일을 한 번만 열어 검증하고 읽기
+    let content = read_template_source(source)?;
+
+    // 템플릿 검사
+    let (errors, warnings): (Vec<_>, Vec<_>) = lint::lint_before_install(&content, config)
+        .into_iter()
+        .partition(|issue| issue.severity == Severity::Error);
+    if !errors.is_empty() {
+        let details: Vec<String> = errors.iter().map(|issue| format!("  {}", issue)).collect();
+        return Err(Error::Validation(t!("template.lint_failed", details.join("\n"))));
+    }
+
+    let store = config.store();
+
+    // 버전 없이 설치하는 경우 기존 버전과 충돌 방지 (번역본은 기본 파일의 버전을 따름)
+    if locale.is_none()
+        && spec.is_latest()
+        && store.find(spec, None)?.is_none()
+        && template_exists(&spec.name, config)?
+    {
+        return Err(Error::Conflict(t!("template.exists_use_version", spec.name)));
+    }
+
+    // 이미 있는 템플릿은 덮어쓰지 않음
+    store.create(spec, locale, &content)?;
+
+    Ok(warnings)
+}
+
+/// 설치할 원본 파일 읽기
+///
+/// 심볼릭 링크를 따라가지 않고 검사한 뒤 파일을 한 번만 열고, 크기와 내용은
+/// 열린 핸들 기준으로 검증해서 검사와 읽기 사이에 파일이 바뀌는 경우를 차단
+pub(crate) fn read_template_source(source: &Path) -> Result<String> {
+    // 심볼릭 링크 차단 (링크 자체의 메타데이터 확인)
+    // 파일이 없거나 권한이 없으면 원인을 담은 I/O 오류 (종료 코드 8)
+    let link_metadata = fs::symlink_metadata(source).map_err(|e| Error::Io {
+        path: source.to_path_buf(),
+        source: e,
+    })?;
+    if link_metadata.file_type().is_symlink() {
+        return Err(Error::Validation(t!("template.symlink").to_string()));
+    }
+    if !link_metadata.is_file() {
+        return Err(Error::Validation(t!("template.regular_file_only", source.display())));
+    }
+
+    let file = File::open(source)
+        .with_context(|| t!("template.open_failed", source.display()))?;
+    let metadata = file
+        .metadata()
+        .with_context(|| t!("template.read_failed", source.display()))?;
+
+    // 검사 후 경로가 다른 파일로 바뀌었는지 확인
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        if metadata.dev() != link_metadata.dev() || metadata.ino() != link_metadata.ino() {
+            return Err(Error::Validation(t!(
+                "template.changed_during_check",
+                source.display()
+            )));
+        }
+    }
+    if !metadata.is_file() {
+        return Err(Error::Validation(t!("template.regular_file_only", source.display())));
     }
 
     // 파일 크기 제한 (10MB)
-    let metadata = fs::metadata(source)?;
-    if metadata.len() > 10 * 1024 * 1024 {
-        anyhow::bail!("파일 크기가 10MB를 초과합니다");
+    if metadata.len() > MAX_TEMPLATE_SIZE {
+        return Err(Error::Validation(t!("template.too_large").to_string()));
     }
 
-    // 심볼릭 링크 차단
-    if metadata.file_type().is_symlink() {
-        anyhow::bail!("심볼릭 링크는 지원하지 않습니다");
+    // 읽는 도중 파일이 커진 경우도 제한
+    let mut bytes = Vec::with_capacity(metadata.len() as usize);
+    file.take(MAX_TEMPLATE_SIZE + 1)
+        .read_to_end(&mut bytes)
+        .with_context(|| t!("template.read_failed", source.display()))?;
+    if bytes.len() as u64 > MAX_TEMPLATE_SIZE {
+        return Err(Error::Validation(t!("template.too_large").to_string()));
+    }
+
+    String::from_utf8(bytes).map_err(|_| Error::Validation(t!("template.utf8_only").to_string()))
+}
+
+/// 템플릿 삭제 (버전을 지정하지 않으면 모든 버전, `name@`이면 버전 없는 파일만 삭제)
+///
+/// 템플릿 경로에 설치된 파일만 삭제하고 같은 이름의 내장 템플릿은 그대로 둠.
+/// 지정한 템플릿이 내장 템플릿뿐이면 삭제할 수 없다는 검증 오류
+pub fn remove_template(spec: &TemplateSpec, config: &Config) -> Result<()> {
+    let store = config.installed_store();
+    let targets: Vec<Template> = store
+        .templates()?
+        .into_iter()
+        .filter(|t| t.name == spec.name)
+        .filter(|t| spec.matches_version(t.version.as_deref()))
+        .collect();
+
+    if targets.is_empty() {
+        if EmbeddedStore.resolve(spec).is_ok() {
+            return Err(Error::Validation(t!("template.builtin_remove", spec.name)));
+        }
+        return Err(Error::TemplateNotFound(spec.clone()));
+    }
+
+    for template in targets {
+        store
+            .remove(&template)
+            .with_context(|| t!("template.remove_failed", template.path.display()))?;
+    }
+
+    Ok(())
+}
+
+/// 대상 이름으로 옮기거나 복사할 템플릿 목록 생성 (기존 템플릿 덮어쓰기 방지)
+///
+/// `templates`에서 원본 이름의 모든 버전과 번역본을 대상으로 함
+fn plan_transfer(
+    templates: Vec<Template>,
+    source: &TemplateName,
+    destination: &TemplateName,
+    config: &Config,
+) -> Result<Vec<(Template, TemplateSpec)>> {
+    let templates: Vec<Template> = templates.into_iter().filter(|t| &t.name == source).collect();
+    if templates.is_empty() {
+        return Err(Error::TemplateNotFound(source.clone().into()));
+    }
+
+    if template_exists(destination, config)? {
+        return Err(Error::Conflict(t!("template.exists", destination)));
+    }
+
+    Ok(templates
+        .into_iter()
+        .map(|t| {
+            let spec = TemplateSpec::exact(destination.clone(), t.version.clone());
+            (t, spec)
+        })
+        .collect())
+}
+
+/// 템플릿 이름 변경 (설치된 모든 버전 이동, 다른 템플릿의 참조 갱신)
+///
+/// 새 이름으로 모두 복사하고 참조를 갱신한 뒤 기존 이름을 삭제. 중간에 실패하면
+/// 지금까지의 변경을 역순으로 되돌림. 참조가 갱신된 템플릿 목록을 반환.
+/// 내장 템플릿은 옮길 수 없어 기존 이름이 남으므로 내장 템플릿과 같은 이름은 변경 불가
+/// (설치한 버전만 옮기려면 `template copy` 후 `template remove` 사용)
+pub fn rename_template(
+    old_name: &TemplateName,
+    new_name: &TemplateName,
+    config: &Config,
+) -> Result<Vec<Template>> {
+    /// 되돌릴 작업
+    enum Undo {
+        Remove(Template),
+        Restore(Template, String),
     }
 
-    // 템플릿 디렉토리 생성
-    if !config.template_path.exists() {
-        fs::create_dir_all(&config.template_path)?;
+    if embedded::builtin_names().contains(&old_name.as_str()) {
+        return Err(Error::Validation(t!("template.builtin_rename", old_name)));
     }
 
-    // 대상 경로
-    let dest = config.template_path.join(format!("{}.md", name));
+    let store = config.installed_store();
+    let transfers = plan_transfer(store.templates()?, old_name, new_name, config)?;
+    let mut undo = Vec::new();
 
-    // 중복 확인
-    if dest.exists() {
-        anyhow::bail!("템플릿 '{}'이(가) 이미 존재합니다", name);
+    let result = (|| -> Result<Vec<Template>> {
+        let mut sources = Vec::new();
+        for (template, dest) in &transfers {
+            let content = store
+                .read(template)
+                .and_then(|content| {
+                    let created = store.create(dest, template.locale.as_deref(), &content)?;
+                    undo.push(Undo::Remove(created));
+                    Ok(content)
+                })
+                .with_context(|| t!("template.rename_failed", template.id(), dest))?;
+            sources.push((template, content));
+        }
+
+        // include 또는 extends로 참조하는 템플릿 갱신 (곧 삭제할 기존 이름 제외)
+        let mut updated = Vec::new();
+        for template in store.templates()? {
+            if &template.name == old_name {
+                continue;
+            }
+            let content = store
+                .read(&template)
+                .with_context(|| t!("template.read_file_failed", template.path.display()))?;
+            let replaced = render::replace_template_references(&content, old_name, new_name);
+
+            if replaced != content {
+                save_template_content(&template, &replaced, config)?;
+                undo.push(Undo::Restore(template.clone(), content));
+                updated.push(template);
+            }
+        }
+
+        for (template, content) in sources {
+            store
+                .remove(template)
+                .with_context(|| t!("template.remove_failed", template.id()))?;
+            undo.push(Undo::Restore(template.clone(), content));
+        }
+
+        Ok(updated)
+    })();
+
+    if result.is_err() {
+        for step in undo.into_iter().rev() {
+            // 원래 오류를 보고하기 위해 되돌리기 실패는 무시
+            let _ = match step {
+                Undo::Remove(template) => store.remove(&template),
+                Undo::Restore(template, content) => store
+                    .write(&template.spec(), template.locale.as_deref(), &content)
+                    .map(|_| ()),
+            };
+        }
     }
+    result
+}
 
-    // 파일 복사
-    fs::copy(source, &dest)
-        .with_context(|| format!("템플릿 파일을 복사할 수 없습니다: {:?} -> {:?}", source, dest))?;
+/// 템플릿 복사 (설치된 버전과 내장 템플릿 등 보이는 모든 버전 복사)
+pub fn copy_template(
+    source: &TemplateName,
+    destination: &TemplateName,
+    config: &Config,
+) -> Result<()> {
+    let store = config.store();
+    for (template, dest) in plan_transfer(store.templates()?, source, destination, config)? {
+        store
+            .read(&template)
+            .and_then(|content| store.create(&dest, template.locale.as_deref(), &content))
+            .with_context(|| t!("template.copy_failed", template.id(), dest))?;
+    }
 
     Ok(())
 }
 
-/// 템플릿 삭제
-pub fn remove_template(name: &str, config: &Config) -> Result<()> {
-    let template_path = config.template_path.join(format!("{}.md", name));
+/// 정리 대상 이전 버전 목록 조회 (이름별로 최신 `keep`개를 제외, 0이면 모든 버전)
+///
+/// 내장 템플릿은 지울 수 없으므로 템플릿 경로에 설치된 템플릿만 대상
+pub fn find_prunable_templates(
+    name: Option<&TemplateName>,
+    keep: usize,
+    config: &Config,
+) -> Result<Vec<Template>> {
+    let installed = config.installed_store().templates()?;
+    let all: Vec<Template> = match name {
+        Some(name) => {
+            let all: Vec<Template> = installed.into_iter().filter(|t| &t.name == name).collect();
+            if all.is_empty() {
+                return Err(Error::TemplateNotFound(name.clone().into()));
+            }
+            all
+        }
+        None => installed,
+    };
+    let templates: Vec<&Template> = all.iter().filter(|t| t.locale.is_none()).collect();
+
+    let mut prunable: Vec<Template> = Vec::new();
+    for (i, &template) in templates.iter().enumerate() {
+        // 정렬되어 있으므로 뒤쪽의 같은 이름 항목 수가 곧 더 최신 버전 수
+        let newer = templates[i + 1..]
+            .iter()
+            .filter(|t| t.name == template.name)
+            .count();
 
-    if !template_path.exists() {
-        anyhow::bail!("템플릿 '{}'을(를) 찾을 수 없습니다", name);
+        if newer >= keep {
+            prunable.push(template.clone());
+        }
     }
 
-    fs::remove_file(&template_path)
-        .with_context(|| format!("템플릿을 삭제할 수 없습니다: {:?}", template_path))?;
+    // 정리되는 버전의 번역본도 함께 정리
+    let translations: Vec<Template> = all
+        .iter()
+        .filter(|t| {
+            t.locale.is_some()
+                && prunable
+                    .iter()
+                    .any(|p| p.name == t.name && p.version == t.version)
+        })
+        .cloned()
+        .collect();
+    prunable.extend(translations);
+
+    Ok(prunable)
+}
+
+/// 이전 버전 템플릿 정리
+pub fn prune_templates(templates: &[Template], config: &Config) -> Result<()> {
+    let store = config.installed_store();
+    for template in templates {
+        store
+            .remove(template)
+            .with_context(|| t!("template.remove_failed", template.path.display()))?;
+    }
 
     Ok(())
 }
 
-/// 템플릿 내용 가져오기
-pub fn get_template_content(name: &str, config: &Config) -> Result<String> {
-    let template_path = config.template_path.join(format!("{}.md", name));
+/// 새 템플릿 기본 내용 (프론트 매터 골격 포함)
+fn starter_template_content(name: &TemplateName) -> String {
+    format!(
+        r#"+++
+description = ""
+tags = []
+
+[variables]
+team = "{name}"
++++
+# {{{{ team }}}} 팀 규칙
+
+## 📌 공동 운영 규칙
+
+1. **규칙 제목**
+    - 규칙 내용을 작성하세요.
+
+## 👥 역할 및 행동 지침
 
-    if !template_path.exists() {
-        anyhow::bail!("템플릿 '{}'을(를) 찾을 수 없습니다", name);
+### 1️⃣ 역할 이름
+- **임무**: 역할의 임무를 작성하세요.
+"#
+    )
+}
+
+/// 새 템플릿 생성 (`from`을 지정하면 기존 템플릿 내용을 복사)
+pub fn create_template(
+    spec: &TemplateSpec,
+    from: Option<&TemplateSpec>,
+    config: &Config,
+) -> Result<Template> {
+    if template_exists(&spec.name, config)? {
+        return Err(Error::Conflict(t!("template.exists", spec.name)));
     }
 
-    let content = fs::read_to_string(&template_path)
-        .with_context(|| format!("템플릿 파일을 읽을 수 없습니다: {:?}", template_path))?;
+    let content = match from {
+        Some(source) => get_template_content(source, config)?,
+        None => starter_template_content(&spec.name),
+    };
 
-    Ok(content)
+    config.store().create(spec, None, &content)
 }
 
-/// 기본 템플릿 설치
-pub fn install_default_template(config: &Config) -> Result<()> {
-    // 템플릿 디렉토리 생성
-    if !config.template_path.exists() {
-        fs::create_dir_all(&config.template_path)?;
+/// 여러 템플릿을 차례로 include하는 새 템플릿 생성
+///
+/// 각 템플릿을 버전 없이 참조하므로 조합한 템플릿이 갱신되면 함께 반영됨.
+/// 태그는 조합한 템플릿의 태그를 모두 합침
+pub fn compose_template(spec: &TemplateSpec, parts: &[TemplateSpec], config: &Config) -> Result<Template> {
+    if template_exists(&spec.name, config)? {
+        return Err(Error::Conflict(t!("template.exists", spec.name)));
     }
 
-    // 내장 템플릿 설치
-    for (name, content) in embedded::get_embedded_templates() {
-        let dest = config.template_path.join(format!("{}.md", name));
+    config.store().create(spec, None, &composed_content(parts, config)?)
+}
+
+/// 여러 템플릿을 차례로 include하는 템플릿 내용 (저장하지 않음)
+pub fn composed_content(parts: &[TemplateSpec], config: &Config) -> Result<String> {
+    let mut tags: Vec<String> = Vec::new();
+    let mut includes = Vec::new();
+    for part in parts {
+        let (metadata, _) = render::split_front_matter(&get_template_content(part, config)?)?;
+        for tag in metadata.tags {
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+        includes.push(format!("{{{{> {} }}}}", part));
+    }
+
+    let names: Vec<String> = parts.iter().map(|p| p.to_string()).collect();
+    let description = toml::Value::String(t!("template.composed_description", names.join(", ")));
+    let tags = toml::Value::Array(tags.into_iter().map(toml::Value::String).collect());
+    Ok(format!(
+        "+++\ndescription = {}\ntags = {}\n+++\n{}",
+        description,
+        tags,
+        includes.join("\n")
+    ))
+}
+
+/// 템플릿 내용 저장 (기존 템플릿 덮어쓰기)
+pub fn save_template_content(template: &Template, content: &str, config: &Config) -> Result<()> {
+    config
+        .store()
+        .write(&template.spec(), template.locale.as_deref(), content)
+        .with_context(|| t!("template.save_failed", template.path.display()))?;
+
+    Ok(())
+}
+
+/// 템플릿 내용 가져오기 (버전을 지정하지 않으면 최신 버전)
+pub fn get_template_content(spec: &TemplateSpec, config: &Config) -> Result<String> {
+    config.store().content(spec)
+}
+
+/// 로케일에 맞는 번역본 내용 가져오기 (번역본이 없으면 기본 파일)
+pub fn get_localized_content(
+    spec: &TemplateSpec,
+    locale: Option<&str>,
+    config: &Config,
+) -> Result<String> {
+    config.store().localized_content(spec, locale)
+}
+
+/// 내장 템플릿 설치 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct BuiltinInstall {
+    /// 설치 대상 (번역본은 `이름.<로케일>`)
+    pub name: String,
+    /// 새로 설치했는지 여부 (이미 있어서 건너뛰었으면 false)
+    pub installed: bool,
+}
+
+/// 기본 템플릿 설치
+pub fn install_default_template(config: &Config) -> Result<()> {
+    install_builtin_template(embedded::DEFAULT_TEMPLATE_NAME, config)?;
+    Ok(())
+}
 
+/// 내장 템플릿과 번역본 설치 (이미 있는 파일은 건너뜀)
+pub fn install_builtin_template(name: &str, config: &Config) -> Result<Vec<BuiltinInstall>> {
+    let store = config.installed_store();
+    let mut results = Vec::new();
+    for file in builtin_files(name, config)? {
         // 이미 존재하면 건너뛰기
-        if dest.exists() {
-            continue;
+        let installed = file.current.is_none();
+        if installed {
+            store
+                .write(&file.spec, file.locale, file.original)
+                .with_context(|| t!("template.default_install_failed", file.path.display()))?;
         }
 
-        fs::write(&dest, content)
-            .with_context(|| format!("기본 템플릿을 설치할 수 없습니다: {:?}", dest))?;
+        results.push(BuiltinInstall {
+            name: file.name,
+            installed,
+        });
+    }
+
+    Ok(results)
+}
+
+/// 설치된 내장 템플릿 파일 (기본 파일 또는 번역본)
+#[derive(Debug, Clone)]
+pub struct BuiltinFile {
+    /// 대상 이름 (번역본은 `이름.<로케일>`)
+    pub name: String,
+    pub spec: TemplateSpec,
+    pub locale: Option<&'static str>,
+    pub path: PathBuf,
+    /// 내장된 원본 내용
+    pub original: &'static str,
+    /// 현재 설치된 내용 (설치되어 있지 않으면 None)
+    pub current: Option<String>,
+}
+
+impl BuiltinFile {
+    /// 설치된 내용이 원본과 다른지 여부
+    ///
+    /// 프론트 매터가 추가되기 전에 설치된 사본처럼 메타데이터만 다르면 수정하지 않은 것으로 간주
+    pub fn is_modified(&self) -> bool {
+        self.current
+            .as_deref()
+            .is_some_and(|current| body_of(current) != body_of(self.original))
+    }
+
+    /// 원본과 같은 상태가 아닌지 여부 (수정되었거나 설치되어 있지 않음)
+    pub fn needs_reset(&self) -> bool {
+        self.current.as_deref() != Some(self.original)
+    }
+}
+
+/// 프론트 매터를 제외한 본문 (프론트 매터가 잘못되었으면 전체 내용)
+fn body_of(content: &str) -> &str {
+    render::split_front_matter(content).map_or(content, |(_, body)| body)
+}
+
+/// 내장 템플릿의 기본 파일과 번역본을 설치된 내용과 함께 조회
+pub fn builtin_files(name: &str, config: &Config) -> Result<Vec<BuiltinFile>> {
+    let Some((name, content)) = embedded::find_builtin(name) else {
+        return Err(Error::Other(anyhow::anyhow!(t!(
+            "template.builtin_not_found",
+            name,
+            embedded::builtin_names().join(", ")
+        ))));
+    };
+
+    let spec = TemplateSpec::from(TemplateName::new(name)?);
+    let translations = embedded::get_embedded_translations()
+        .into_iter()
+        .filter(|(base, _, _)| *base == name)
+        .map(|(_, locale, content)| (Some(locale), content));
+
+    // 내장 템플릿 자체가 아니라 템플릿 경로에 설치된 파일과 비교
+    let store = config.installed_store();
+    std::iter::once((None, content))
+        .chain(translations)
+        .map(|(locale, original)| {
+            let (path, current) = match store.find(&spec, locale)? {
+                Some(template) => {
+                    let current = store
+                        .read(&template)
+                        .with_context(|| t!("template.read_file_failed", template.path.display()))?;
+                    (template.path, Some(current))
+                }
+                None => (config.template_path.join(file_name_for(&spec, locale)), None),
+            };
+            Ok(BuiltinFile {
+                name: locale.map_or(name.to_string(), |locale| format!("{}.{}", name, locale)),
+                spec: spec.clone(),
+                locale,
+                path,
+                original,
+                current,
+            })
+        })
+        .collect()
+}
+
+/// 설치된 템플릿이 내용이 수정된 내장 템플릿인지 확인 (번역본 포함)
+pub fn is_modified_builtin(template: &Template, config: &Config) -> Result<bool> {
+    let is_builtin = template.version.is_none()
+        && embedded::find_builtin(template.name.as_str())
+            .is_some_and(|(name, _)| name == template.name.as_str());
+    if !is_builtin {
+        return Ok(false);
+    }
+
+    Ok(builtin_files(template.name.as_str(), config)?
+        .iter()
+        .any(BuiltinFile::is_modified))
+}
+
+/// 내장 템플릿 파일을 원본 내용으로 되돌리기 (설치되어 있지 않으면 새로 설치)
+pub fn reset_builtin_files(files: &[&BuiltinFile], config: &Config) -> Result<()> {
+    let store = config.installed_store();
+    for file in files {
+        store
+            .write(&file.spec, file.locale, file.original)
+            .with_context(|| t!("template.save_failed", file.path.display()))?;
     }
 
     Ok(())
@@ -145,13 +759,28 @@ mod tests {
     use super::*;
     use tempfile::tempdir;
 
+    fn spec(s: &str) -> TemplateSpec {
+        TemplateSpec::parse(s).unwrap()
+    }
+
+    fn name(s: &str) -> TemplateName {
+        TemplateName::new(s).unwrap()
+    }
+
+    /// 템플릿 경로에 설치된 템플릿 수 (번역본 제외, 이름별 최신 버전)
+    fn installed(config: &Config) -> usize {
+        config.installed_store().list().unwrap().len()
+    }
+
     #[test]
     fn test_list_templates_empty() {
         let temp_dir = tempdir().unwrap();
         let config = Config::new(temp_dir.path().to_path_buf());
 
+        // 설치된 템플릿이 없어도 내장 템플릿은 보임
         let templates = list_templates(&config).unwrap();
-        assert_eq!(templates.len(), 0);
+        assert_eq!(templates.len(), embedded::builtin_names().len());
+        assert_eq!(installed(&config), 0);
     }
 
     #[test]
@@ -161,9 +790,123 @@ mod tests {
 
         install_default_template(&config).unwrap();
 
-        let templates = list_templates(&config).unwrap();
+        let templates = config.installed_store().list().unwrap();
         assert_eq!(templates.len(), 1);
         assert_eq!(templates[0].name, "Programming-Team");
+        // 설치한 템플릿이 같은 이름의 내장 템플릿을 가림
+        assert_eq!(list_templates(&config).unwrap().len(), embedded::builtin_names().len());
+    }
+
+    #[test]
+    fn test_builtin_templates_with_config() {
+        let temp_dir = tempdir().unwrap();
+        let config = Config::new(temp_dir.path().to_path_buf());
+        fs::write(temp_dir.path().join("Rules.md"), "# Rules\n").unwrap();
+
+        // 설정이 있어도 설치하지 않은 내장 템플릿 사용 가능
+        let content = get_template_content(&spec("Frontend"), &config).unwrap();
+        assert_eq!(content, embedded::find_builtin("Frontend").unwrap().1);
+
+        // 내장 템플릿을 편집하면 템플릿 경로에 작성되고, 내장 템플릿은 삭제할 수 없음
+        let frontend = resolve_template(&spec("Frontend"), &config).unwrap();
+        save_template_content(&frontend, "# Custom\n", &config).unwrap();
+        assert_eq!(fs::read_to_string(temp_dir.path().join("Frontend.md")).unwrap(), "# Custom\n");
+        remove_template(&spec("Frontend"), &config).unwrap();
+        assert!(matches!(
+            remove_template(&spec("Frontend"), &config),
+            Err(Error::Validation(_))
+        ));
+        assert_eq!(installed(&config), 1);
+    }
+
+    #[test]
+    fn test_remove_installed_version_of_builtin() {
+        let temp_dir = tempdir().unwrap();
+        let config = Config::new(temp_dir.path().to_path_buf());
+        fs::write(temp_dir.path().join("Code-Review@2.0.md"), "# Review 2.0\n").unwrap();
+
+        // 설치한 버전만 삭제하고 내장 템플릿은 남김
+        remove_template(&spec("Code-Review"), &config).unwrap();
+        assert!(!temp_dir.path().join("Code-Review@2.0.md").exists());
+        let builtin = resolve_template(&spec("Code-Review"), &config).unwrap();
+        assert_eq!(builtin.version, None);
+
+        // 내장 템플릿만 남은 이름이나 버전 없는 파일은 삭제할 수 없음
+        fs::write(temp_dir.path().join("Code-Review@2.0.md"), "# Review 2.0\n").unwrap();
+        assert!(matches!(
+            remove_template(&spec("Code-Review@"), &config),
+            Err(Error::Validation(_))
+        ));
+        assert!(matches!(
+            remove_template(&spec("Code-Review@3.0"), &config),
+            Err(Error::TemplateNotFound(_))
+        ));
+        assert!(temp_dir.path().join("Code-Review@2.0.md").exists());
+    }
+
+    #[test]
+    fn test_install_builtin_templates() {
+        let temp_dir = tempdir().unwrap();
+        let config = Config::new(temp_dir.path().to_path_buf());
+
+        // 모든 내장 템플릿은 검사 오류 없이 기본값으로 렌더링됨
+        for name in embedded::builtin_names() {
+            let results = install_builtin_template(name, &config).unwrap();
+            assert!(results.iter().all(|r| r.installed));
+
+            let content = get_template_content(&spec(name), &config).unwrap();
+            let issues = lint::lint_content(&content, Some(&config));
+            assert!(issues.iter().all(|i| i.severity != Severity::Error), "{}", name);
+            let (metadata, _) = render::split_front_matter(&content).unwrap();
+            assert!(metadata.description.is_some(), "{}", name);
+            render::render_template(&spec(name), &Default::default(), &config).unwrap();
+        }
+
+        // 번역본도 함께 설치되고, 이미 있는 파일은 건너뜀 (삭제한 파일만 복원)
+        fs::remove_file(temp_dir.path().join("Frontend.md")).unwrap();
+        let results = install_builtin_template("programming-team", &config).unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|r| !r.installed));
+        let results = install_builtin_template("Frontend", &config).unwrap();
+        assert!(results[0].installed);
+
+        assert!(install_builtin_template("Unknown", &config).is_err());
+    }
+
+    #[test]
+    fn test_reset_builtin_template() {
+        let temp_dir = tempdir().unwrap();
+        let config = Config::new(temp_dir.path().to_path_buf());
+        install_default_template(&config).unwrap();
+
+        let template = resolve_template(&spec("Programming-Team"), &config).unwrap();
+        assert!(!is_modified_builtin(&template, &config).unwrap());
+
+        // 프론트 매터가 없던 이전 버전의 사본은 수정된 것이 아니지만 되돌리기 대상
+        let original = embedded::find_builtin("Programming-Team").unwrap().1;
+        let (_, body) = render::split_front_matter(original).unwrap();
+        fs::write(&template.path, body).unwrap();
+        assert!(!is_modified_builtin(&template, &config).unwrap());
+        let files = builtin_files("Programming-Team", &config).unwrap();
+        assert!(files[0].needs_reset());
+        reset_builtin_files(&[&files[0]], &config).unwrap();
+
+        // 번역본만 수정되어도 수정된 것으로 표시
+        fs::write(temp_dir.path().join("Programming-Team.en.md"), "edited").unwrap();
+        assert!(is_modified_builtin(&template, &config).unwrap());
+
+        let files = builtin_files("Programming-Team", &config).unwrap();
+        let changed: Vec<&BuiltinFile> = files.iter().filter(|f| f.needs_reset()).collect();
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].name, "Programming-Team.en");
+
+        reset_builtin_files(&changed, &config).unwrap();
+        assert!(!is_modified_builtin(&template, &config).unwrap());
+
+        // 같은 이름의 버전 템플릿이나 내장되지 않은 템플릿은 해당 없음
+        fs::write(temp_dir.path().join("Frontend@2.0.md"), "custom").unwrap();
+        let custom = resolve_template(&spec("Frontend"), &config).unwrap();
+        assert!(!is_modified_builtin(&custom, &config).unwrap());
     }
 
     #[test]
@@ -173,7 +916,398 @@ mod tests {
 
         install_default_template(&config).unwrap();
 
-        let content = get_template_content("Programming-Team", &config).unwrap();
+        let content = get_template_content(&spec("Programming-Team"), &config).unwrap();
         assert!(content.contains("AI Software Engineering Team System"));
     }
+
+    #[test]
+    fn test_compose_template() {
+        let temp_dir = tempdir().unwrap();
+        let config = Config::new(temp_dir.path().to_path_buf());
+        fs::write(temp_dir.path().join("Base.md"), "+++\ntags = [\"core\"]\n+++\n# Base\n").unwrap();
+        fs::write(
+            temp_dir.path().join("Extra.md"),
+            "+++\ntags = [\"core\", \"extra\"]\n[variables]\nteam = \"A\"\n+++\n# Extra {{ team }}\n",
+        )
+        .unwrap();
+
+        let parts = [spec("Base"), spec("Extra")];
+        let composed = compose_template(&spec("Base+Extra"), &parts, &config).unwrap();
+        let content = fs::read_to_string(&composed.path).unwrap();
+        let (metadata, _) = render::split_front_matter(&content).unwrap();
+        assert_eq!(metadata.tags, ["core", "extra"]);
+
+        // 조합한 템플릿 사이에 빈 줄을 두고, 각 템플릿의 변수 기본값을 그대로 사용
+        let rendered = render::render_template(&spec("Base+Extra"), &Default::default(), &config).unwrap();
+        assert_eq!(rendered, "# Base\n\n# Extra A\n");
+
+        assert!(compose_template(&spec("Base"), &parts, &config).is_err());
+    }
+
+    #[test]
+    fn test_compare_versions() {
+        assert_eq!(compare_versions(Some("1.10"), Some("1.2")), Ordering::Greater);
+        assert_eq!(compare_versions(Some("1.2"), Some("1.2.0")), Ordering::Equal);
+        assert_eq!(compare_versions(Some("01.2"), Some("1.2")), Ordering::Equal);
+        assert_eq!(compare_versions(None, Some("0.1")), Ordering::Less);
+
+        // 숫자와 접미사가 섞여도 한 가지 순서로 정렬
+        let mut versions = vec![
+            "1.9", "2.0", "1.10", "1.9a", "1.beta", "2.0-rc1", "1.9b", "1.0", "99999999999999999999",
+        ];
+        versions.sort_by(|a, b| compare_versions(Some(a), Some(b)));
+        assert_eq!(
+            versions,
+            ["1.beta", "1.0", "1.9a", "1.9b", "1.9", "1.10", "2.0-rc1", "2.0", "99999999999999999999"]
+        );
+        for (i, a) in versions.iter().enumerate() {
+            for (j, b) in versions.iter().enumerate() {
+                assert_eq!(compare_versions(Some(a), Some(b)), i.cmp(&j), "{} {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_template_versions() {
+        let temp_dir = tempdir().unwrap();
+        let source_dir = tempdir().unwrap();
+        let config = Config::new(temp_dir.path().to_path_buf());
+
+        for version in ["1.0", "1.2", "1.10"] {
+            let source = source_dir.path().join(format!("source-{}.md", version));
+            fs::write(&source, format!("# Rules {}", version)).unwrap();
+            install_template(&source, &spec(&format!("Rules@{}", version)), &config).unwrap();
+        }
+
+        // 이름별 최신 버전만 표시
+        let templates = config.installed_store().list().unwrap();
+        assert_eq!(templates.len(), 1);
+        assert_eq!(templates[0].id(), "Rules@1.10");
+        assert_eq!(
+            installed_path(&templates[0], &config).unwrap(),
+            Some(temp_dir.path().join("Rules@1.10.md"))
+        );
+        let builtin = resolve_template(&spec(embedded::DEFAULT_TEMPLATE_NAME), &config).unwrap();
+        assert_eq!(installed_path(&builtin, &config).unwrap(), None);
+        assert_eq!(resolve_template(&spec("Rules"), &config).unwrap().id(), "Rules@1.10");
+        assert_eq!(config.installed_store().versions().unwrap().len(), 3);
+
+        // 버전 지정 및 최신 버전 기본 선택
+        assert_eq!(get_template_content(&spec("Rules"), &config).unwrap(), "# Rules 1.10");
+        assert_eq!(get_template_content(&spec("Rules@1.2"), &config).unwrap(), "# Rules 1.2");
+
+        // 같은 버전 또는 버전 없는 이름으로 재설치 불가
+        let source = source_dir.path().join("source-1.0.md");
+        assert!(install_template(&source, &spec("Rules@1.0"), &config).is_err());
+        assert!(install_template(&source, &spec("Rules"), &config).is_err());
+
+        // 최신 버전만 남기고 정리
+        let prunable = find_prunable_templates(Some(&name("Rules")), 1, &config).unwrap();
+        assert_eq!(prunable.len(), 2);
+        prune_templates(&prunable, &config).unwrap();
+        assert_eq!(config.installed_store().versions().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_unversioned_template_alongside_versions() {
+        let temp_dir = tempdir().unwrap();
+        let source_dir = tempdir().unwrap();
+        let config = Config::new(temp_dir.path().to_path_buf());
+        let source = source_dir.path().join("source.md");
+
+        // 버전만 있으면 `이름@`은 찾을 수 없고, 버전 없는 파일은 이름@으로 따로 설치 가능
+        fs::write(&source, "# Rules 1.0").unwrap();
+        install_template(&source, &spec("Rules@1.0"), &config).unwrap();
+        assert!(matches!(
+            resolve_template(&spec("Rules@"), &config),
+            Err(Error::TemplateNotFound(_))
+        ));
+        assert!(matches!(
+            remove_template(&spec("Rules@"), &config),
+            Err(Error::TemplateNotFound(_))
+        ));
+        fs::write(&source, "# Rules").unwrap();
+        install_template(&source, &spec("Rules@"), &config).unwrap();
+
+        // 이름만 쓰면 최신 버전, `이름@`은 버전 없는 파일
+        assert_eq!(get_template_content(&spec("Rules"), &config).unwrap(), "# Rules 1.0");
+        assert_eq!(get_template_content(&spec("Rules@"), &config).unwrap(), "# Rules");
+        assert_eq!(resolve_template(&spec("Rules@"), &config).unwrap().id(), "Rules");
+
+        // 버전 없는 파일만 삭제
+        remove_template(&spec("Rules@"), &config).unwrap();
+        assert!(!temp_dir.path().join("Rules.md").exists());
+        assert!(temp_dir.path().join("Rules@1.0.md").exists());
+    }
+
+    #[test]
+    fn test_localized_templates() {
+        let temp_dir = tempdir().unwrap();
+        let config = Config::new(temp_dir.path().to_path_buf());
+
+        install_default_template(&config).unwrap();
+        fs::write(temp_dir.path().join("Rules@1.0.md"), "기본 1.0").unwrap();
+        fs::write(temp_dir.path().join("Rules@1.0.en.md"), "english 1.0").unwrap();
+        fs::write(temp_dir.path().join("Rules@1.1.md"), "기본 1.1").unwrap();
+
+        // 번역본은 목록과 버전 목록에 포함되지 않음
+        let templates = config.installed_store().list().unwrap();
+        assert_eq!(templates.len(), 2);
+        assert_eq!(config.installed_store().versions().unwrap().len(), 3);
+        assert_eq!(list_locales(&templates[0], &config).unwrap(), vec!["en"]);
+
+        // 완전 일치, 언어만 일치, 번역본 없음
+        let content = get_localized_content(&spec("Programming-Team"), Some("en"), &config);
+        assert!(content.unwrap().contains("written in **English**"));
+        let content = get_localized_content(&spec("Programming-Team"), Some("en_US"), &config);
+        assert!(content.unwrap().contains("written in **English**"));
+        let content = get_localized_content(&spec("Programming-Team"), Some("ja"), &config);
+        assert!(content.unwrap().contains("**한국어**"));
+
+        // 번역본은 기본 파일과 같은 버전에서만 선택
+        let content = get_localized_content(&spec("Rules"), Some("en"), &config).unwrap();
+        assert_eq!(content, "기본 1.1");
+        let content = get_localized_content(&spec("Rules@1.0"), Some("en"), &config).unwrap();
+        assert_eq!(content, "english 1.0");
+
+        // 정리되는 버전의 번역본도 함께 정리
+        let prunable = find_prunable_templates(Some(&name("Rules")), 1, &config).unwrap();
+        assert_eq!(prunable.len(), 2);
+        prune_templates(&prunable, &config).unwrap();
+        assert!(!temp_dir.path().join("Rules@1.0.en.md").exists());
+
+        // 번역본 설치, 로케일이 아닌 접미사는 이름의 일부
+        let source = temp_dir.path().join("source.md");
+        fs::write(&source, "english 1.1").unwrap();
+        install_localized_template(&source, &spec("Rules@1.1"), Some("en"), &config).unwrap();
+        let content = get_localized_content(&spec("Rules"), Some("en"), &config).unwrap();
+        assert_eq!(content, "english 1.1");
+        install_template(&source, &spec("api.js"), &config).unwrap();
+        assert!(temp_dir.path().join("api.js.md").exists());
+        assert_eq!(resolve_template(&spec("api.js"), &config).unwrap().locale, None);
+    }
+
+    #[test]
+    fn test_create_template() {
+        let temp_dir = tempdir().unwrap();
+        let config = Config::new(temp_dir.path().to_path_buf());
+
+        create_template(&spec("Starter"), None, &config).unwrap();
+        let content = get_template_content(&spec("Starter"), &config).unwrap();
+        assert!(content.starts_with("+++\n"));
+        assert!(content.contains("team = \"Starter\""));
+        assert!(crate::render::validate_content(&content).is_ok());
+
+        create_template(&spec("Copy"), Some(&spec("Starter")), &config).unwrap();
+        assert_eq!(get_template_content(&spec("Copy"), &config).unwrap(), content);
+
+        // 이미 존재하는 이름은 생성 불가
+        assert!(create_template(&spec("Starter"), None, &config).is_err());
+    }
+
+    #[test]
+    fn test_rename_and_copy_template() {
+        let temp_dir = tempdir().unwrap();
+        let config = Config::new(temp_dir.path().to_path_buf());
+
+        fs::write(temp_dir.path().join("Base@1.0.md"), "base 1.0").unwrap();
+        fs::write(temp_dir.path().join("Base@2.0.md"), "base 2.0").unwrap();
+        fs::write(temp_dir.path().join("Child.md"), "{{> Base }}\nchild").unwrap();
+
+        // 이미 존재하는 이름으로 변경 불가
+        assert!(rename_template(&name("Base"), &name("Child"), &config).is_err());
+
+        let updated = rename_template(&name("Base"), &name("Core"), &config).unwrap();
+        assert_eq!(updated.len(), 1);
+        assert_eq!(updated[0].name, "Child");
+        assert_eq!(get_template_content(&spec("Child"), &config).unwrap(), "{{> Core }}\nchild");
+        assert!(resolve_template(&spec("Base"), &config).is_err());
+        assert_eq!(get_template_content(&spec("Core@1.0"), &config).unwrap(), "base 1.0");
+
+        copy_template(&name("Core"), &name("Copied"), &config).unwrap();
+        assert_eq!(config.installed_store().versions().unwrap().len(), 5);
+        assert!(copy_template(&name("Core"), &name("Copied"), &config).is_err());
+    }
+
+    #[test]
+    fn test_rename_rejects_builtin_name() {
+        let temp_dir = tempdir().unwrap();
+        let config = Config::new(temp_dir.path().to_path_buf());
+        fs::write(temp_dir.path().join("Code-Review@2.0.md"), "# Review 2.0\n").unwrap();
+
+        // 내장 템플릿은 옮길 수 없으므로 아무것도 바꾸지 않고 거부
+        assert!(matches!(
+            rename_template(&name("Code-Review"), &name("CR"), &config),
+            Err(Error::Validation(_))
+        ));
+        assert!(temp_dir.path().join("Code-Review@2.0.md").exists());
+        assert!(!template_exists(&name("CR"), &config).unwrap());
+
+        // 복사는 설치한 버전과 내장 템플릿을 모두 새 이름으로 복사
+        copy_template(&name("Code-Review"), &name("CR"), &config).unwrap();
+        let copied: Vec<Option<String>> = list_template_versions(&config)
+            .unwrap()
+            .into_iter()
+            .filter(|t| t.name == "CR")
+            .map(|t| t.version)
+            .collect();
+        assert_eq!(copied, vec![None, Some("2.0".to_string())]);
+
+        // 설치한 템플릿만 있는 이름은 변경 가능
+        rename_template(&name("CR"), &name("Review"), &config).unwrap();
+        assert!(temp_dir.path().join("Review@2.0.md").exists());
+        assert!(!template_exists(&name("CR"), &config).unwrap());
+    }
+
+    #[test]
+    fn test_rename_template_rolls_back_on_failure() {
+        let temp_dir = tempdir().unwrap();
+        let config = Config::new(temp_dir.path().to_path_buf());
+
+        fs::write(temp_dir.path().join("Base@1.0.md"), "base 1.0").unwrap();
+        fs::write(temp_dir.path().join("Base@2.0.md"), "base 2.0").unwrap();
+        fs::write(temp_dir.path().join("Child.md"), "{{> Base }}").unwrap();
+        // 두 번째 버전을 옮길 자리에 디렉토리가 있어 도중에 실패
+        fs::create_dir(temp_dir.path().join("Core@2.0.md")).unwrap();
+
+        assert!(rename_template(&name("Base"), &name("Core"), &config).is_err());
+        assert!(!temp_dir.path().join("Core@1.0.md").exists());
+        assert_eq!(get_template_content(&spec("Base@1.0"), &config).unwrap(), "base 1.0");
+        assert_eq!(get_template_content(&spec("Base@2.0"), &config).unwrap(), "base 2.0");
+        assert_eq!(get_template_content(&spec("Child"), &config).unwrap(), "{{> Base }}");
+    }
+
+    #[test]
+    fn test_entry_points_reject_traversal() {
+        let root = tempdir().unwrap();
+        let template_dir = root.path().join("templates");
+        let config = Config::new(template_dir.clone());
+        install_default_template(&config).unwrap();
+
+        // 템플릿 디렉토리 바깥의 파일
+        let secret = root.path().join("secret.md");
+        fs::write(&secret, "secret").unwrap();
+        let source = root.path().join("source.md");
+        fs::write(&source, "source").unwrap();
+
+        for attempt in ["../secret", "../../etc/x", "sub/../../secret", "..\\secret", "x@../../secret"] {
+            // 모든 템플릿 API는 검증된 이름만 받으므로 지정자 파싱 단계에서 거부
+            assert!(TemplateSpec::parse(attempt).is_err(), "{}", attempt);
+            assert!(TemplateName::new(attempt).is_err(), "{}", attempt);
+        }
+
+        // 렌더링 중 include/extends로 지정된 이름도 거부
+        fs::write(template_dir.join("Include.md"), "{{> ../secret }}").unwrap();
+        fs::write(template_dir.join("Extends.md"), "+++\nextends = \"../secret\"\n+++\n").unwrap();
+        let variables = std::collections::BTreeMap::new();
+        assert!(render::render_template(&spec("Include"), &variables, &config).is_err());
+        assert!(render::render_template(&spec("Extends"), &variables, &config).is_err());
+
+        // 검증된 이름으로의 동작은 템플릿 디렉토리 안에서만 이루어짐
+        install_template(&source, &spec("Safe"), &config).unwrap();
+        assert!(template_dir.join("Safe.md").exists());
+        remove_template(&spec("Safe"), &config).unwrap();
+        assert!(get_template_content(&spec("secret"), &config).is_err());
+        assert!(remove_template(&spec("secret"), &config).is_err());
+        assert!(rename_template(&name("secret"), &name("stolen"), &config).is_err());
+        assert!(copy_template(&name("secret"), &name("stolen"), &config).is_err());
+        assert_eq!(fs::read_to_string(&secret).unwrap(), "secret");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_install_rejects_symlink() {
+        let temp_dir = tempdir().unwrap();
+        let source_dir = tempdir().unwrap();
+        let config = Config::new(temp_dir.path().to_path_buf());
+
+        let target = source_dir.path().join("target.md");
+        fs::write(&target, "# Rules").unwrap();
+        let link = source_dir.path().join("link.md");
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+
+        let err = install_template(&link, &spec("Linked"), &config).unwrap_err();
+        assert!(matches!(err, Error::Validation(_)));
+        assert_eq!(err.to_string(), t!("template.symlink"));
+        assert!(!temp_dir.path().join("Linked.md").exists());
+
+        // 대상이 없는 링크도 링크 자체를 확인해서 거부 (파일 없음으로 안내하지 않음)
+        let dangling = source_dir.path().join("dangling.md");
+        std::os::unix::fs::symlink(source_dir.path().join("missing.md"), &dangling).unwrap();
+        let err = install_template(&dangling, &spec("Dangling"), &config).unwrap_err();
+        assert!(matches!(err, Error::Validation(_)));
+        assert_eq!(installed(&config), 0);
+    }
+
+    #[test]
+    fn test_install_rejects_invalid_sources() {
+        let temp_dir = tempdir().unwrap();
+        let source_dir = tempdir().unwrap();
+        let config = Config::new(temp_dir.path().to_path_buf());
+
+        // 10MB 초과 파일
+        let oversized = source_dir.path().join("big.md");
+        File::create(&oversized)
+            .unwrap()
+            .set_len(MAX_TEMPLATE_SIZE + 1)
+            .unwrap();
+        let err = install_template(&oversized, &spec("Big"), &config).unwrap_err();
+        assert!(err.to_string().contains("10MB"));
+
+        // UTF-8이 아닌 파일
+        let binary = source_dir.path().join("binary.md");
+        fs::write(&binary, [0xff, 0xfe, 0x00]).unwrap();
+        assert!(install_template(&binary, &spec("Binary"), &config).is_err());
+
+        // 없는 파일과 그 밖의 읽기 오류는 원인을 담은 I/O 오류
+        let missing = source_dir.path().join("missing.md");
+        let err = install_template(&missing, &spec("Missing"), &config).unwrap_err();
+        match &err {
+            Error::Io { path, source } => {
+                assert_eq!(path, &missing);
+                assert_eq!(source.kind(), std::io::ErrorKind::NotFound);
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert_eq!(err.exit_code(), crate::error::EXIT_IO);
+        let not_dir = binary.join("nested.md");
+        let err = install_template(&not_dir, &spec("Nested"), &config).unwrap_err();
+        assert!(matches!(err, Error::Io { .. }));
+
+        // 디렉토리
+        let directory = source_dir.path().join("dir.md");
+        fs::create_dir(&directory).unwrap();
+        assert!(install_template(&directory, &spec("Dir"), &config).is_err());
+
+        // 검사 오류가 있는 템플릿
+        let broken = source_dir.path().join("broken.md");
+        fs::write(&broken, "# Rules\n{{#if strict}}\n").unwrap();
+        let err = install_template(&broken, &spec("Broken"), &config).unwrap_err();
+        assert!(err.to_string().contains("unbalanced-conditional"));
+        assert!(!temp_dir.path().join("Broken.md").exists());
+
+        assert_eq!(installed(&config), 0);
+    }
+
+    #[test]
+    fn test_install_with_missing_include() {
+        let temp_dir = tempdir().unwrap();
+        let source_dir = tempdir().unwrap();
+        let config = Config::new(temp_dir.path().to_path_buf());
+        let rules = source_dir.path().join("Rules.md");
+        fs::write(&rules, "# Rules\n{{> Common }}\n").unwrap();
+        let common = source_dir.path().join("Common.md");
+        fs::write(&common, "## Common\n").unwrap();
+
+        // 참조 대상보다 먼저 설치해도 경고만 표시
+        let warnings = install_template(&rules, &spec("Rules"), &config).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].rule, "broken-include");
+        assert_eq!(warnings[0].severity, Severity::Warning);
+        // 검사에서는 여전히 오류
+        assert!(lint::lint_template(&spec("Rules"), &config).unwrap().has_errors());
+
+        install_template(&common, &spec("Common"), &config).unwrap();
+        assert!(!lint::lint_template(&spec("Rules"), &config).unwrap().has_errors());
+    }
 }
@@ -0,0 +1,90 @@
+use serde::Serialize;
+use std::fmt;
+
+use crate::i18n::t;
+
+/// 토큰 수 추정치
+///
+/// 실제 토크나이저 없이 계산하는 근사치이므로 여러 방식의 결과를 함께 보관하고,
+/// 한도 확인에는 가장 큰 값을 사용
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub struct TokenEstimate {
+    /// 문자 4개당 1토큰 (영문 기준 GPT 계열 토크나이저의 경험칙)
+    pub chars: usize,
+    /// 단어 1개당 4/3토큰 (영문 단어 기준 경험칙)
+    pub words: usize,
+    /// ASCII 문자 4개당 1토큰 + 그 외 문자 1개당 1토큰 (한글 등 비영문 문서에 적합)
+    pub mixed: usize,
+}
+
+impl TokenEstimate {
+    pub fn of(text: &str) -> Self {
+        let mut char_count: usize = 0;
+        let mut ascii_count: usize = 0;
+        for c in text.chars() {
+            char_count += 1;
+            if c.is_ascii() {
+                ascii_count += 1;
+            }
+        }
+        let word_count = text.split_whitespace().count();
+
+        Self {
+            chars: char_count.div_ceil(4),
+            words: (word_count * 4).div_ceil(3),
+            mixed: ascii_count.div_ceil(4) + (char_count - ascii_count),
+        }
+    }
+
+    /// 가장 보수적인(큰) 추정치
+    pub fn max(&self) -> usize {
+        self.chars.max(self.words).max(self.mixed)
+    }
+}
+
+impl fmt::Display for TokenEstimate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            t!("tokens.estimate", self.max(), self.chars, self.words, self.mixed)
+        )
+    }
+}
+
+/// 템플릿과 설정의 한도 중 더 작은 값
+pub fn effective_limit(template_limit: Option<usize>, config_limit: Option<usize>) -> Option<usize> {
+    match (template_limit, config_limit) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate() {
+        assert_eq!(TokenEstimate::of(""), TokenEstimate::default());
+
+        let estimate = TokenEstimate::of("Write tests first");
+        assert_eq!(estimate.chars, 5);
+        assert_eq!(estimate.words, 4);
+        assert_eq!(estimate.mixed, 5);
+        assert_eq!(estimate.max(), 5);
+
+        // 비영문 문자는 문자당 1토큰
+        let estimate = TokenEstimate::of("테스트 먼저");
+        assert_eq!(estimate.mixed, 6);
+        assert_eq!(estimate.max(), 6);
+    }
+
+    #[test]
+    fn test_effective_limit() {
+        assert_eq!(effective_limit(None, None), None);
+        assert_eq!(effective_limit(Some(100), None), Some(100));
+        assert_eq!(effective_limit(None, Some(50)), Some(50));
+        assert_eq!(effective_limit(Some(100), Some(50)), Some(50));
+    }
+}